@@ -0,0 +1,235 @@
+use std::cmp::Ordering;
+use std::ops::{Add, AddAssign, Mul};
+
+// A non-negative number that keeps growing past u64 and f64 instead of overflowing, so blind
+// targets and scores never wrap.
+// Below 2^53 it's a plain f64 (exponent 0), which is exact for whole numbers, so a score that
+// displays the same as its target also compares equal to it. Above that it's stored as
+// mantissa x 10^exponent, with the mantissa in [1, 10)
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BigNum {
+    mantissa: f64,
+    exponent: i64,
+}
+
+// Below this many digits the number is printed in full
+const FULL_DIGITS: i64 = 15;
+
+// Largest value an f64 holds every whole number up to (2^53)
+const EXACT_LIMIT: f64 = 9_007_199_254_740_992.0;
+
+impl BigNum {
+    pub const ZERO: BigNum = BigNum { mantissa: 0.0, exponent: 0 };
+
+    pub fn from_f64(value: f64) -> Self {
+        if value <= 0.0 || !value.is_finite() {
+            return Self::ZERO;
+        }
+        Self { mantissa: value, exponent: 0 }.normalized()
+    }
+
+    // The number 10^`log`
+    pub fn from_log10(log: f64) -> Self {
+        let exponent = log.floor();
+        Self { mantissa: 10f64.powf(log - exponent), exponent: exponent as i64 }.normalized()
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.mantissa == 0.0
+    }
+
+    // Whether this is stored as a plain f64
+    fn is_exact(&self) -> bool {
+        self.exponent == 0
+    }
+
+    // Round down to the given number of significant digits
+    pub fn floor_significant(&self, digits: i32) -> Self {
+        if self.is_zero() {
+            return *self;
+        }
+        let (mantissa, exponent) = self.scientific();
+        if self.is_exact() {
+            // Work on the plain value so whole numbers stay exact, e.g. 11234 becomes 11000 rather than 11000.000000000002
+            let unit = 10f64.powi(exponent as i32 - digits + 1);
+            // Nudge up slightly so float error doesn't turn e.g. 2.0 into 1.9
+            return Self::from_f64(((self.mantissa / unit) + 1e-9).floor() * unit);
+        }
+        let scale = 10f64.powi(digits - 1);
+        let mantissa = ((mantissa * scale) + 1e-9).floor() / scale;
+        Self { mantissa, exponent }.normalized()
+    }
+
+    // The number as mantissa x 10^exponent, with the mantissa in [1, 10)
+    fn scientific(&self) -> (f64, i64) {
+        if self.is_zero() || !self.is_exact() {
+            return (self.mantissa, self.exponent);
+        }
+        let shift = self.mantissa.log10().floor();
+        let mut mantissa = self.mantissa / 10f64.powf(shift);
+        let mut exponent = shift as i64;
+        // log10 can be off by one right at a power of 10
+        if mantissa >= 10.0 {
+            mantissa /= 10.0;
+            exponent += 1;
+        } else if mantissa < 1.0 {
+            mantissa *= 10.0;
+            exponent -= 1;
+        }
+        (mantissa, exponent)
+    }
+
+    // Bring the number back to a plain f64 if it fits, or to a mantissa in [1, 10) if it doesn't
+    fn normalized(self) -> Self {
+        if self.mantissa <= 0.0 || !self.mantissa.is_finite() {
+            return Self::ZERO;
+        }
+        if self.is_exact() && self.mantissa < EXACT_LIMIT {
+            return self;
+        }
+        let shift = self.mantissa.log10().floor();
+        let (mut mantissa, mut exponent) = (self.mantissa / 10f64.powf(shift), self.exponent + shift as i64);
+        // log10 can be off by one right at a power of 10
+        if mantissa >= 10.0 {
+            mantissa /= 10.0;
+            exponent += 1;
+        } else if mantissa < 1.0 {
+            mantissa *= 10.0;
+            exponent -= 1;
+        }
+        if exponent <= FULL_DIGITS + 1 {
+            let value = mantissa * 10f64.powi(exponent as i32);
+            if value < EXACT_LIMIT {
+                return Self { mantissa: value, exponent: 0 };
+            }
+        }
+        Self { mantissa, exponent }
+    }
+}
+
+impl From<u64> for BigNum {
+    fn from(value: u64) -> Self {
+        Self::from_f64(value as f64)
+    }
+}
+
+impl Add for BigNum {
+    type Output = BigNum;
+
+    fn add(self, other: BigNum) -> BigNum {
+        if self.is_exact() && other.is_exact() {
+            return Self { mantissa: self.mantissa + other.mantissa, exponent: 0 }.normalized();
+        }
+        let (big, small) = if self >= other { (self, other) } else { (other, self) };
+        if small.is_zero() {
+            return big;
+        }
+        let ((big_mantissa, big_exponent), (small_mantissa, small_exponent)) = (big.scientific(), small.scientific());
+        let gap = big_exponent - small_exponent;
+        if gap > FULL_DIGITS + 2 {
+            return big;  // too small to make a difference
+        }
+        Self {
+            mantissa: big_mantissa + small_mantissa / 10f64.powi(gap as i32),
+            exponent: big_exponent,
+        }.normalized()
+    }
+}
+
+impl AddAssign for BigNum {
+    fn add_assign(&mut self, other: BigNum) {
+        *self = *self + other;
+    }
+}
+
+impl Mul for BigNum {
+    type Output = BigNum;
+
+    fn mul(self, other: BigNum) -> BigNum {
+        if self.is_exact() && other.is_exact() {
+            return Self { mantissa: self.mantissa * other.mantissa, exponent: 0 }.normalized();
+        }
+        let ((mantissa, exponent), (other_mantissa, other_exponent)) = (self.scientific(), other.scientific());
+        Self {
+            mantissa: mantissa * other_mantissa,
+            exponent: exponent + other_exponent,
+        }.normalized()
+    }
+}
+
+impl Mul<f64> for BigNum {
+    type Output = BigNum;
+
+    fn mul(self, factor: f64) -> BigNum {
+        Self { mantissa: self.mantissa * factor, exponent: self.exponent }.normalized()
+    }
+}
+
+impl PartialOrd for BigNum {
+    fn partial_cmp(&self, other: &BigNum) -> Option<Ordering> {
+        let ordering = match (self.is_exact(), other.is_exact()) {
+            (true, true) => self.mantissa.total_cmp(&other.mantissa),
+            // Anything not stored exactly is past the limit, so bigger than anything that is
+            (true, false) => Ordering::Less,
+            (false, true) => Ordering::Greater,
+            (false, false) => self.exponent.cmp(&other.exponent)
+                .then(self.mantissa.total_cmp(&other.mantissa)),
+        };
+        Some(ordering)
+    }
+}
+
+impl std::fmt::Display for BigNum {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (mantissa, exponent) = self.scientific();
+        if exponent < FULL_DIGITS {
+            write!(f, "{}", self.mantissa.round() as u64)
+        } else {
+            write!(f, "{:.3}e{}", mantissa, exponent)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn exact_products_match_their_value() {
+        let score = BigNum::from_f64(3.0) * BigNum::from_f64(12.0);
+        assert_eq!(score, BigNum::from(36));
+        assert!(score >= BigNum::from(36));
+        assert_eq!(BigNum::from_f64(30.0) * BigNum::from_f64(1.5), BigNum::from(45));
+    }
+
+    #[test]
+    fn add_and_mul_across_the_exact_limit() {
+        let limit = BigNum::from(1 << 53);
+        assert_eq!(limit.to_string(), "9.007e15");
+        assert!(limit > BigNum::from((1 << 53) - 1));
+        assert!(BigNum::from((1 << 53) - 1) + BigNum::from(1) >= limit);
+
+        let big = BigNum::from_log10(20.0);
+        assert_eq!(big.to_string(), "1.000e20");
+        assert_eq!((big * BigNum::from(2)).to_string(), "2.000e20");
+        assert_eq!((big + BigNum::from(5)).to_string(), "1.000e20");
+        assert!(big > BigNum::from(u64::MAX / 10_000));
+        assert!(BigNum::from(1_000) < big);
+    }
+
+    #[test]
+    fn comparisons_order_by_size() {
+        assert!(BigNum::ZERO < BigNum::from(1));
+        assert!(BigNum::from(299) < BigNum::from(300));
+        assert!(BigNum::from_log10(30.0) < BigNum::from_log10(30.5));
+        assert!(BigNum::from_log10(31.0) > BigNum::from_log10(30.9));
+        assert_eq!(BigNum::from_f64(-5.0), BigNum::ZERO);
+    }
+
+    #[test]
+    fn floor_significant_keeps_whole_numbers_exact() {
+        assert_eq!(BigNum::from(11_234).floor_significant(2), BigNum::from(11_000));
+        assert_eq!(BigNum::from(20_000).floor_significant(2), BigNum::from(20_000));
+        assert_eq!(BigNum::from_log10(20.5).floor_significant(2).to_string(), "3.100e20");
+    }
+}
@@ -1,12 +1,44 @@
+use crate::{Player, PokerHand};
+use crate::bignum::BigNum;
 use crate::cards::{Card, Suit};
+use crate::stakes::RunConfig;
 use rand::seq::SliceRandom;
+use rand::Rng;
 
-pub const ANTES: [u64; 15] = [
-    100, 300, 800, 2000, 5000, 11_000, 20_000, 35_000, 50_000,
-    110_000, 560_000, 7_200_000, 300_000_000, 47_000_000_000, 29_000_000_000_000, // additional antes cut off for sake of simplicity
+// Base score of each ante's Small Blind for antes 1-8, at each ante scaling:
+// 1 for White Stake, 2 from Green Stake and 3 from Purple Stake
+const ANTE_AMOUNTS: [[u64; 8]; 3] = [
+    [300, 800, 2000, 5000, 11_000, 20_000, 35_000, 50_000],
+    [300, 900, 2600, 8000, 20_000, 36_000, 60_000, 100_000],
+    [300, 1000, 3200, 9000, 25_000, 60_000, 110_000, 200_000],
 ];
 
-#[derive(Clone, Copy)]
+// Base score of an ante's Small Blind under the run's ante scaling. Past ante 8 this follows the
+// game's endless formula, a * (b + (k * c)^d)^c rounded down to 2 significant digits, where a is
+// the ante 8 amount, b = 1.6, k = 0.75, c = antes past 8 and d = 1 + 0.2c
+pub fn ante_base_score(ante: u8, config: &RunConfig) -> BigNum {
+    let amounts = &ANTE_AMOUNTS[config.ante_scaling() as usize - 1];
+    if ante < 1 {
+        return BigNum::from(100);
+    }
+    if ante <= 8 {
+        return BigNum::from(amounts[ante as usize - 1]);
+    }
+
+    // Worked out in powers of 10, since it passes what an f64 can hold after a few dozen antes
+    let (a, b, k) = (amounts[7] as f64, 1.6, 0.75);
+    let c = (ante - 8) as f64;
+    let d = 1.0 + 0.2 * c;
+    let log_growth = d * (k * c).log10();
+    let log_base = if log_growth < 15.0 {
+        (b + 10f64.powf(log_growth)).log10()
+    } else {
+        log_growth  // b is far too small to matter
+    };
+    BigNum::from_log10(a.log10() + c * log_base).floor_significant(2)
+}
+
+#[derive(Clone, Copy, PartialEq)]
 pub enum BlindType {
     Small,
     Big,
@@ -25,15 +57,80 @@ impl std::fmt::Display for BlindType {
 
 pub struct Blind {
     pub name: String,
-    pub score: u64,
+    pub score: BigNum,
     pub description: String,
     pub boss_ability: Option<Box<dyn BossBlindAbility>>,
 }
 
+// What's happened so far this ante, for bosses that look back at it
+#[derive(Default)]
+pub struct AnteHistory {
+    pub cards_played: Vec<Card>,
+}
+
+// When cards are being dealt, for bosses that deal some of them face-down
+#[derive(Clone, Copy, PartialEq)]
+pub enum DealContext {
+    FirstHand,
+    AfterPlay,
+    AfterDiscard,
+}
+
+// Every hook besides the name and description is optional, so each boss only implements what it needs
 pub trait BossBlindAbility {
     fn name(&self) -> &str;
     fn description(&self) -> &str;
-    fn is_card_debuffed(&self, card: &Card) -> bool;
+
+    // Change the blind's target score
+    fn modify_target(&self, score: BigNum) -> BigNum {
+        score
+    }
+
+    // Permanent-for-this-round change to hand size, undone once the round ends
+    fn hand_size_change(&self) -> i8 {
+        0
+    }
+
+    // Called once the hand is dealt at the start of the round (e.g. to change hands or discards)
+    fn on_round_start(&mut self, _player: &mut Player, _history: &AnteHistory) {}
+
+    // Called before a hand is scored. An error means the hand is played but doesn't score
+    fn check_hand(&self, _cards: &[Card], _hand_type: &PokerHand) -> Result<(), String> {
+        Ok(())
+    }
+
+    // Called before an allowed hand is scored
+    fn before_score(&mut self, _player: &mut Player, _hand_type: &PokerHand) {}
+
+    // Change the base chips and mult of the played hand
+    fn modify_base_score(&self, _chips: &mut f64, _mult: &mut f64) {}
+
+    // Called after a hand is played and the played cards have left the hand, before new cards are dealt
+    fn after_play(&mut self, _player: &mut Player, _cards: &[Card], _hand_type: &PokerHand) {}
+
+    // A fixed number of cards to draw, instead of filling the hand back up to the hand size
+    fn cards_to_draw(&self, _context: DealContext) -> Option<usize> {
+        None
+    }
+
+    // Whether a card being dealt into hand should be face-down
+    fn is_dealt_face_down(&mut self, _card: &Card, _context: DealContext) -> bool {
+        false
+    }
+
+    // A card in hand that has to be part of every hand played or discarded
+    fn forced_card(&mut self, _hand: &[Card]) -> Option<usize> {
+        None
+    }
+
+    fn on_joker_sold(&mut self) {}
+
+    // Called once the blind is beaten, to undo anything that shouldn't outlast the round
+    fn on_round_end(&mut self, _player: &mut Player) {}
+
+    fn is_card_debuffed(&self, _card: &Card) -> bool {
+        false
+    }
 }
 
 pub struct TheClub;
@@ -48,7 +145,7 @@ impl BossBlindAbility for TheClub {
     }
 
     fn is_card_debuffed(&self, card: &Card) -> bool {
-        card.suit == Suit::Clubs
+        card.is_suit(&Suit::Clubs)
     }
 }
 
@@ -64,7 +161,7 @@ impl BossBlindAbility for TheGoad {
     }
 
     fn is_card_debuffed(&self, card: &Card) -> bool {
-        card.suit == Suit::Spades
+        card.is_suit(&Suit::Spades)
     }
 }
 
@@ -80,7 +177,7 @@ impl BossBlindAbility for TheWindow {
     }
 
     fn is_card_debuffed(&self, card: &Card) -> bool {
-        card.suit == Suit::Diamonds
+        card.is_suit(&Suit::Diamonds)
     }
 }
 
@@ -96,7 +193,509 @@ impl BossBlindAbility for TheHead {
     }
 
     fn is_card_debuffed(&self, card: &Card) -> bool {
-        card.suit == Suit::Hearts
+        card.is_suit(&Suit::Hearts)
+    }
+}
+
+pub struct ThePsychic;
+
+impl BossBlindAbility for ThePsychic {
+    fn name(&self) -> &str {
+        "The Psychic"
+    }
+
+    fn description(&self) -> &str {
+        "Must play 5 cards"
+    }
+
+    fn check_hand(&self, cards: &[Card], _hand_type: &PokerHand) -> Result<(), String> {
+        if cards.len() == 5 {
+            Ok(())
+        } else {
+            Err("The Psychic requires exactly 5 cards".to_string())
+        }
+    }
+}
+
+pub struct TheEye {
+    played: Vec<PokerHand>,  // hand types already played this round
+}
+
+impl BossBlindAbility for TheEye {
+    fn name(&self) -> &str {
+        "The Eye"
+    }
+
+    fn description(&self) -> &str {
+        "No repeat hand types this round"
+    }
+
+    fn check_hand(&self, _cards: &[Card], hand_type: &PokerHand) -> Result<(), String> {
+        if self.played.contains(hand_type) {
+            Err(format!("{} has already been played this round", hand_type))
+        } else {
+            Ok(())
+        }
+    }
+
+    fn after_play(&mut self, _player: &mut Player, _cards: &[Card], hand_type: &PokerHand) {
+        if !self.played.contains(hand_type) {
+            self.played.push(*hand_type);
+        }
+    }
+}
+
+pub struct TheMouth {
+    allowed: Option<PokerHand>,  // set by the first hand played this round
+}
+
+impl BossBlindAbility for TheMouth {
+    fn name(&self) -> &str {
+        "The Mouth"
+    }
+
+    fn description(&self) -> &str {
+        "Play only 1 hand type this round"
+    }
+
+    fn check_hand(&self, _cards: &[Card], hand_type: &PokerHand) -> Result<(), String> {
+        match self.allowed {
+            Some(allowed) if allowed != *hand_type => Err(format!("Only {} can be played this round", allowed)),
+            _ => Ok(()),
+        }
+    }
+
+    fn after_play(&mut self, _player: &mut Player, _cards: &[Card], hand_type: &PokerHand) {
+        if self.allowed.is_none() {
+            self.allowed = Some(*hand_type);
+        }
+    }
+}
+
+pub struct TheNeedle;
+
+impl BossBlindAbility for TheNeedle {
+    fn name(&self) -> &str {
+        "The Needle"
+    }
+
+    fn description(&self) -> &str {
+        "Play only 1 hand"
+    }
+
+    // Only half the usual boss target, to make up for the single hand
+    fn modify_target(&self, score: BigNum) -> BigNum {
+        score * 0.5
+    }
+
+    fn on_round_start(&mut self, player: &mut Player, _history: &AnteHistory) {
+        player.hands = 1;
+    }
+}
+
+pub struct TheWater;
+
+impl BossBlindAbility for TheWater {
+    fn name(&self) -> &str {
+        "The Water"
+    }
+
+    fn description(&self) -> &str {
+        "Start with 0 discards"
+    }
+
+    fn on_round_start(&mut self, player: &mut Player, _history: &AnteHistory) {
+        player.discards = 0;
+    }
+}
+
+pub struct TheHook;
+
+impl BossBlindAbility for TheHook {
+    fn name(&self) -> &str {
+        "The Hook"
+    }
+
+    fn description(&self) -> &str {
+        "Discards 2 random cards held in hand after every played hand"
+    }
+
+    fn after_play(&mut self, player: &mut Player, _cards: &[Card], _hand_type: &PokerHand) {
+        let mut rng = rand::thread_rng();
+        let indices: Vec<usize> = rand::seq::index::sample(&mut rng, player.cards_in_hand.len(), player.cards_in_hand.len().min(2)).into_vec();
+        if !indices.is_empty() {
+            println!("{} discards:", self.name());
+            player.discard_cards(&indices, true, true);
+        }
+    }
+}
+
+pub struct TheSerpent;
+
+impl BossBlindAbility for TheSerpent {
+    fn name(&self) -> &str {
+        "The Serpent"
+    }
+
+    fn description(&self) -> &str {
+        "After Play or Discard, always draw 3 cards"
+    }
+
+    fn cards_to_draw(&self, context: DealContext) -> Option<usize> {
+        match context {
+            DealContext::FirstHand => None,
+            DealContext::AfterPlay | DealContext::AfterDiscard => Some(3),
+        }
+    }
+}
+
+pub struct TheHouse;
+
+impl BossBlindAbility for TheHouse {
+    fn name(&self) -> &str {
+        "The House"
+    }
+
+    fn description(&self) -> &str {
+        "First hand is drawn face down"
+    }
+
+    fn is_dealt_face_down(&mut self, _card: &Card, context: DealContext) -> bool {
+        context == DealContext::FirstHand
+    }
+}
+
+pub struct TheWheel;
+
+impl BossBlindAbility for TheWheel {
+    fn name(&self) -> &str {
+        "The Wheel"
+    }
+
+    fn description(&self) -> &str {
+        "1 in 7 cards get drawn face down"
+    }
+
+    fn is_dealt_face_down(&mut self, _card: &Card, _context: DealContext) -> bool {
+        rand::thread_rng().gen_range(0..7) == 0
+    }
+}
+
+pub struct TheFish;
+
+impl BossBlindAbility for TheFish {
+    fn name(&self) -> &str {
+        "The Fish"
+    }
+
+    fn description(&self) -> &str {
+        "Cards drawn face down after each hand played"
+    }
+
+    fn is_dealt_face_down(&mut self, _card: &Card, context: DealContext) -> bool {
+        context == DealContext::AfterPlay
+    }
+}
+
+pub struct TheMark;
+
+impl BossBlindAbility for TheMark {
+    fn name(&self) -> &str {
+        "The Mark"
+    }
+
+    fn description(&self) -> &str {
+        "All face cards are drawn face down"
+    }
+
+    fn is_dealt_face_down(&mut self, card: &Card, _context: DealContext) -> bool {
+        !card.is_stone() && card.rank.is_face()
+    }
+}
+
+pub struct TheFlint;
+
+impl BossBlindAbility for TheFlint {
+    fn name(&self) -> &str {
+        "The Flint"
+    }
+
+    fn description(&self) -> &str {
+        "Base Chips and Mult are halved"
+    }
+
+    fn modify_base_score(&self, chips: &mut f64, mult: &mut f64) {
+        *chips = (*chips / 2.0).ceil();
+        *mult = (*mult / 2.0).ceil().max(1.0);
+    }
+}
+
+pub struct TheArm;
+
+impl BossBlindAbility for TheArm {
+    fn name(&self) -> &str {
+        "The Arm"
+    }
+
+    fn description(&self) -> &str {
+        "Decrease level of played poker hand"
+    }
+
+    fn before_score(&mut self, player: &mut Player, hand_type: &PokerHand) {
+        if player.hand_levels.level_down(hand_type) {
+            println!("{}: {} down to lvl.{}", self.name(), hand_type, player.hand_levels.get(hand_type).level);
+        }
+    }
+}
+
+pub struct TheWall;
+
+impl BossBlindAbility for TheWall {
+    fn name(&self) -> &str {
+        "The Wall"
+    }
+
+    fn description(&self) -> &str {
+        "Extra large blind"
+    }
+
+    fn modify_target(&self, score: BigNum) -> BigNum {
+        score * 2.0
+    }
+}
+
+pub struct TheOx {
+    most_played: Option<PokerHand>,  // as of the start of the round
+}
+
+impl BossBlindAbility for TheOx {
+    fn name(&self) -> &str {
+        "The Ox"
+    }
+
+    fn description(&self) -> &str {
+        "Playing your most played hand this run sets money to $0"
+    }
+
+    fn on_round_start(&mut self, player: &mut Player, _history: &AnteHistory) {
+        self.most_played = player.hand_levels.most_played();
+        if let Some(hand) = self.most_played {
+            println!("{}: don't play {}!", self.name(), hand);
+        }
+    }
+
+    fn after_play(&mut self, player: &mut Player, _cards: &[Card], hand_type: &PokerHand) {
+        if self.most_played == Some(*hand_type) {
+            player.money = 0;
+            println!("{}: money set to $0", self.name());
+        }
+    }
+}
+
+pub struct TheTooth;
+
+impl BossBlindAbility for TheTooth {
+    fn name(&self) -> &str {
+        "The Tooth"
+    }
+
+    fn description(&self) -> &str {
+        "Lose $1 per card played"
+    }
+
+    fn after_play(&mut self, player: &mut Player, cards: &[Card], _hand_type: &PokerHand) {
+        player.money -= cards.len() as i32;
+        println!("{}: -${}", self.name(), cards.len());
+    }
+}
+
+pub struct TheManacle;
+
+impl BossBlindAbility for TheManacle {
+    fn name(&self) -> &str {
+        "The Manacle"
+    }
+
+    fn description(&self) -> &str {
+        "-1 Hand Size"
+    }
+
+    fn hand_size_change(&self) -> i8 {
+        -1
+    }
+}
+
+pub struct ThePillar {
+    played_this_ante: Vec<u64>,  // card ids, so copies of a played card aren't debuffed
+}
+
+impl BossBlindAbility for ThePillar {
+    fn name(&self) -> &str {
+        "The Pillar"
+    }
+
+    fn description(&self) -> &str {
+        "Cards played previously this Ante are debuffed"
+    }
+
+    fn on_round_start(&mut self, _player: &mut Player, history: &AnteHistory) {
+        self.played_this_ante = history.cards_played.iter().map(|card| card.id).collect();
+    }
+
+    fn after_play(&mut self, _player: &mut Player, cards: &[Card], _hand_type: &PokerHand) {
+        self.played_this_ante.extend(cards.iter().map(|card| card.id));
+    }
+
+    fn is_card_debuffed(&self, card: &Card) -> bool {
+        self.played_this_ante.contains(&card.id)
+    }
+}
+
+pub struct ThePlant;
+
+impl BossBlindAbility for ThePlant {
+    fn name(&self) -> &str {
+        "The Plant"
+    }
+
+    fn description(&self) -> &str {
+        "All face cards are debuffed"
+    }
+
+    fn is_card_debuffed(&self, card: &Card) -> bool {
+        !card.is_stone() && card.rank.is_face()
+    }
+}
+
+// === Showdown boss blinds, only found on the final ante ===
+
+pub struct VerdantLeaf {
+    joker_sold: bool,
+}
+
+impl BossBlindAbility for VerdantLeaf {
+    fn name(&self) -> &str {
+        "Verdant Leaf"
+    }
+
+    fn description(&self) -> &str {
+        "All cards debuffed until 1 Joker sold"
+    }
+
+    fn on_joker_sold(&mut self) {
+        if !self.joker_sold {
+            self.joker_sold = true;
+            println!("{}: cards are no longer debuffed", self.name());
+        }
+    }
+
+    fn is_card_debuffed(&self, _card: &Card) -> bool {
+        !self.joker_sold
+    }
+}
+
+pub struct VioletVessel;
+
+impl BossBlindAbility for VioletVessel {
+    fn name(&self) -> &str {
+        "Violet Vessel"
+    }
+
+    fn description(&self) -> &str {
+        "Very large blind"
+    }
+
+    fn modify_target(&self, score: BigNum) -> BigNum {
+        score * 3.0
+    }
+}
+
+pub struct AmberAcorn;
+
+impl BossBlindAbility for AmberAcorn {
+    fn name(&self) -> &str {
+        "Amber Acorn"
+    }
+
+    fn description(&self) -> &str {
+        "Flips and shuffles all Joker cards"
+    }
+
+    fn on_round_start(&mut self, player: &mut Player, _history: &AnteHistory) {
+        let mut rng = rand::thread_rng();
+        player.jokers.shuffle(&mut rng);
+        for joker in player.jokers.iter_mut() {
+            joker.face_down = true;
+        }
+    }
+
+    fn on_round_end(&mut self, player: &mut Player) {
+        for joker in player.jokers.iter_mut() {
+            joker.face_down = false;
+        }
+    }
+}
+
+pub struct CrimsonHeart;
+
+impl CrimsonHeart {
+    fn disable_random_joker(player: &mut Player) {
+        let mut rng = rand::thread_rng();
+        for joker in player.jokers.iter_mut() {
+            joker.disabled = false;
+        }
+        if let Some(joker) = player.jokers.choose_mut(&mut rng) {
+            joker.disabled = true;
+        }
+    }
+}
+
+impl BossBlindAbility for CrimsonHeart {
+    fn name(&self) -> &str {
+        "Crimson Heart"
+    }
+
+    fn description(&self) -> &str {
+        "One random Joker disabled every hand"
+    }
+
+    fn on_round_start(&mut self, player: &mut Player, _history: &AnteHistory) {
+        CrimsonHeart::disable_random_joker(player);
+    }
+
+    fn after_play(&mut self, player: &mut Player, _cards: &[Card], _hand_type: &PokerHand) {
+        CrimsonHeart::disable_random_joker(player);
+    }
+
+    fn on_round_end(&mut self, player: &mut Player) {
+        for joker in player.jokers.iter_mut() {
+            joker.disabled = false;
+        }
+    }
+}
+
+pub struct CeruleanBell {
+    forced: Option<Card>,
+}
+
+impl BossBlindAbility for CeruleanBell {
+    fn name(&self) -> &str {
+        "Cerulean Bell"
+    }
+
+    fn description(&self) -> &str {
+        "Forces 1 card to always be selected"
+    }
+
+    // Keep forcing the same card while it's in hand, otherwise pick a new one
+    fn forced_card(&mut self, hand: &[Card]) -> Option<usize> {
+        if let Some(idx) = self.forced.as_ref().and_then(|forced| hand.iter().position(|card| card == forced)) {
+            return Some(idx);
+        }
+        let mut rng = rand::thread_rng();
+        let idx = (!hand.is_empty()).then(|| rng.gen_range(0..hand.len()))?;
+        self.forced = Some(hand[idx].clone());
+        Some(idx)
     }
 }
 
@@ -109,45 +708,173 @@ impl BossBlindFactory {
             "The Goad" => Box::new(TheGoad {}),
             "The Window" => Box::new(TheWindow {}),
             "The Head" => Box::new(TheHead {}),
+            "The Psychic" => Box::new(ThePsychic {}),
+            "The Eye" => Box::new(TheEye { played: Vec::new() }),
+            "The Mouth" => Box::new(TheMouth { allowed: None }),
+            "The Needle" => Box::new(TheNeedle {}),
+            "The Water" => Box::new(TheWater {}),
+            "The Hook" => Box::new(TheHook {}),
+            "The Serpent" => Box::new(TheSerpent {}),
+            "The House" => Box::new(TheHouse {}),
+            "The Wheel" => Box::new(TheWheel {}),
+            "The Fish" => Box::new(TheFish {}),
+            "The Mark" => Box::new(TheMark {}),
+            "The Flint" => Box::new(TheFlint {}),
+            "The Arm" => Box::new(TheArm {}),
+            "The Wall" => Box::new(TheWall {}),
+            "The Ox" => Box::new(TheOx { most_played: None }),
+            "The Tooth" => Box::new(TheTooth {}),
+            "The Manacle" => Box::new(TheManacle {}),
+            "The Pillar" => Box::new(ThePillar { played_this_ante: Vec::new() }),
+            "The Plant" => Box::new(ThePlant {}),
+            "Verdant Leaf" => Box::new(VerdantLeaf { joker_sold: false }),
+            "Violet Vessel" => Box::new(VioletVessel {}),
+            "Amber Acorn" => Box::new(AmberAcorn {}),
+            "Crimson Heart" => Box::new(CrimsonHeart {}),
+            "Cerulean Bell" => Box::new(CeruleanBell { forced: None }),
             _ => panic!("Unknown boss blind ability: {}", name),
         }
     }
 }
 
+// Regular boss blinds and the earliest ante each can show up on
+pub const BOSS_BLINDS: [(&str, u8); 23] = [
+    ("The Club", 1),
+    ("The Goad", 1),
+    ("The Window", 1),
+    ("The Head", 1),
+    ("The Psychic", 1),
+    ("The Hook", 1),
+    ("The Manacle", 1),
+    ("The Pillar", 1),
+    ("The Eye", 3),
+    ("The Mouth", 2),
+    ("The Needle", 2),
+    ("The Water", 2),
+    ("The Serpent", 5),
+    ("The House", 2),
+    ("The Wheel", 2),
+    ("The Fish", 2),
+    ("The Mark", 2),
+    ("The Flint", 2),
+    ("The Arm", 2),
+    ("The Wall", 2),
+    ("The Ox", 6),
+    ("The Tooth", 3),
+    ("The Plant", 4),
+];
+
+// Showdown bosses replace the regular ones on the final ante (and every 8th ante after)
+pub const SHOWDOWN_BOSS_BLINDS: [&str; 5] = [
+    "Verdant Leaf",
+    "Violet Vessel",
+    "Amber Acorn",
+    "Crimson Heart",
+    "Cerulean Bell",
+];
+
+pub const FINAL_ANTE: u8 = 8;
+
+// Every boss blind that can show up on this ante
+pub fn boss_pool(ante: u8) -> Vec<&'static str> {
+    if ante > 0 && ante.is_multiple_of(FINAL_ANTE) {
+        return SHOWDOWN_BOSS_BLINDS.to_vec();
+    }
+    BOSS_BLINDS
+        .iter()
+        .filter(|(_, min_ante)| ante.max(1) >= *min_ante)
+        .map(|(name, _)| *name)
+        .collect()
+}
+
+// A random boss for this ante, never the same as `previous` unless it's the only choice
+pub fn random_boss(ante: u8, previous: Option<&str>) -> &'static str {
+    let mut rng = rand::thread_rng();
+    let pool = boss_pool(ante);
+    let choices: Vec<&'static str> = pool.iter().copied().filter(|&name| Some(name) != previous).collect();
+    match choices.choose(&mut rng) {
+        Some(name) => name,
+        None => pool[0],
+    }
+}
+
 impl Blind {
-    pub fn new(blind_type: BlindType, ante: u8) -> Self {
+    pub fn new(blind_type: BlindType, ante: u8, config: &RunConfig) -> Self {
         match blind_type {
             BlindType::Small => Self {
                 name: "Small Blind".to_string(),
-                score: ANTES[ante as usize],  // despite there being an ante 0, we start at 1
+                score: ante_base_score(ante, config),  // despite there being an ante 0, we start at 1
                 description: "".to_string(),
                 boss_ability: None,
             },
             BlindType::Big => Self {
                 name: "Big Blind".to_string(), 
-                score: (ANTES[ante as usize] as f64 * 1.5) as u64,
+                score: ante_base_score(ante, config) * 1.5,
                 description: "".to_string(),
                 boss_ability: None,
             },
-            BlindType::Boss => {
-                let ability = {
-                    let boss_blinds = vec![  // pick a boss blind at random
-                        "The Club",
-                        "The Goad",
-                        "The Window",
-                        "The Head",
-                    ];
-                    let mut rng = rand::thread_rng();
-                    let random_boss = boss_blinds.choose(&mut rng).unwrap();
-                    BossBlindFactory::create_boss_blind(random_boss)
-                };
-                Self {
-                    name: format!("Boss Blind - {}", ability.name()),
-                    score: ANTES[ante as usize] * 2,
-                    description: ability.description().to_string(),
-                    boss_ability: Some(ability),
-                }
-            },
+            BlindType::Boss => Self::boss(random_boss(ante, None), ante, config),
         }
     }
-}
\ No newline at end of file
+
+    pub fn boss(name: &str, ante: u8, config: &RunConfig) -> Self {
+        let ability = BossBlindFactory::create_boss_blind(name);
+        Self {
+            name: format!("Boss Blind - {}", ability.name()),
+            score: ability.modify_target(ante_base_score(ante, config) * 2.0),
+            description: ability.description().to_string(),
+            boss_ability: Some(ability),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stakes::Stake;
+
+    #[test]
+    fn ante_base_scores_follow_each_curve() {
+        let curves = [
+            (Stake::White, [300, 800, 2000, 5000, 11_000, 20_000, 35_000, 50_000]),
+            (Stake::Green, [300, 900, 2600, 8000, 20_000, 36_000, 60_000, 100_000]),
+            (Stake::Purple, [300, 1000, 3200, 9000, 25_000, 60_000, 110_000, 200_000]),
+        ];
+        for (stake, amounts) in curves {
+            let config = RunConfig::new(stake);
+            assert_eq!(ante_base_score(0, &config), BigNum::from(100));
+            for (ante, amount) in (1..=8).zip(amounts) {
+                assert_eq!(ante_base_score(ante, &config), BigNum::from(amount), "{:?} ante {}", stake, ante);
+            }
+        }
+    }
+
+    #[test]
+    fn endless_ante_base_scores() {
+        let config = RunConfig::new(Stake::White);
+        let expected = [
+            (9, "110000"),
+            (10, "560000"),
+            (11, "7200000"),
+            (12, "300000000"),
+            (13, "47000000000"),
+            (14, "29000000000000"),
+            (15, "7.700e16"),
+            (16, "8.600e20"),
+        ];
+        for (ante, score) in expected {
+            assert_eq!(ante_base_score(ante, &config).to_string(), score, "ante {}", ante);
+        }
+        assert_eq!(ante_base_score(9, &RunConfig::new(Stake::Purple)), BigNum::from(460_000));
+        assert!(ante_base_score(100, &config) > ante_base_score(99, &config));
+    }
+
+    #[test]
+    fn pillar_only_debuffs_the_cards_played() {
+        let played: Card = "Kh".parse().unwrap();
+        let copy = played.duplicate();
+        let pillar = ThePillar { played_this_ante: vec![played.id] };
+        assert!(pillar.is_card_debuffed(&played));
+        assert!(!pillar.is_card_debuffed(&copy));
+    }
+}
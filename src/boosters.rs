@@ -0,0 +1,164 @@
+use colored::*;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use crate::Player;
+use crate::cards::Card;
+use crate::consumables::{planet_for_hand, random_spectral, random_tarot, ConsumableAbility, ConsumableFactory, PLANETS};
+use crate::jokers::{Joker, JokerFactory, Rarity};
+use crate::vouchers::Voucher;
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum PackKind {
+    Standard,  // playing cards added to the deck
+    Arcana,  // tarot cards, used right away on a freshly dealt hand
+    Celestial,  // planet cards, used right away
+    Buffoon,  // jokers
+    Spectral,  // spectral cards, used right away on a freshly dealt hand
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum PackSize {
+    Normal,
+    Jumbo,
+    Mega,
+}
+
+// Something that can be picked out of an opened booster pack
+pub enum PackChoice {
+    Card(Card),
+    Joker(Joker),
+    Consumable(Box<dyn ConsumableAbility>),
+}
+
+impl std::fmt::Display for PackChoice {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PackChoice::Card(card) => write!(f, "{}", card),
+            PackChoice::Joker(joker) => write!(f, "[Joker - {}] {}: {}", joker.ability.rarity(), joker.name(), joker.description()),
+            PackChoice::Consumable(consumable) => write!(f, "[{}] {}: {}", consumable.kind(), consumable.name(), consumable.description()),
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct BoosterPack {
+    pub kind: PackKind,
+    pub size: PackSize,
+}
+
+impl BoosterPack {
+    pub fn new(kind: PackKind, size: PackSize) -> Self {
+        Self { kind, size }
+    }
+
+    // Buffoon and Spectral packs are the rarest, and bigger packs are rarer than Normal ones
+    pub fn random() -> Self {
+        let mut rng = rand::thread_rng();
+        let kind = match rng.gen_range(0..100) {
+            0..=29 => PackKind::Standard,
+            30..=59 => PackKind::Arcana,
+            60..=89 => PackKind::Celestial,
+            90..=96 => PackKind::Buffoon,
+            _ => PackKind::Spectral,
+        };
+        let size = match rng.gen_range(0..13) {
+            0..=7 => PackSize::Normal,
+            8..=11 => PackSize::Jumbo,
+            _ => PackSize::Mega,
+        };
+        Self::new(kind, size)
+    }
+
+    pub fn price(&self) -> i32 {
+        match self.size {
+            PackSize::Normal => 4,
+            PackSize::Jumbo => 6,
+            PackSize::Mega => 8,
+        }
+    }
+
+    // How many cards are shown when the pack is opened
+    pub fn choices(&self) -> usize {
+        let base = match self.kind {
+            PackKind::Buffoon | PackKind::Spectral => 2,
+            _ => 3,
+        };
+        match self.size {
+            PackSize::Normal => base,
+            PackSize::Jumbo | PackSize::Mega => base + 2,
+        }
+    }
+
+    // How many of the shown cards may be picked
+    pub fn picks(&self) -> usize {
+        match self.size {
+            PackSize::Normal | PackSize::Jumbo => 1,
+            PackSize::Mega => 2,
+        }
+    }
+
+    // Arcana and Spectral cards are used immediately, so they need a hand to act on
+    pub fn needs_hand(&self) -> bool {
+        matches!(self.kind, PackKind::Arcana | PackKind::Spectral)
+    }
+
+    pub fn description(&self) -> String {
+        let contents = match self.kind {
+            PackKind::Standard => "Playing cards to add to your deck",
+            PackKind::Arcana => "Tarot cards to be used immediately",
+            PackKind::Celestial => "Planet cards to be used immediately",
+            PackKind::Buffoon => "Joker cards",
+            PackKind::Spectral => "Spectral cards to be used immediately",
+        };
+        format!("Choose {} of up to {} {}", self.picks().to_string().bold(), self.choices().to_string().bold(), contents)
+    }
+
+    pub fn generate_choices(&self, player: &Player) -> Vec<PackChoice> {
+        let mut rng = rand::thread_rng();
+        let mut choices: Vec<PackChoice> = (0..self.choices())
+            .map(|_| match self.kind {
+                PackKind::Standard => PackChoice::Card(Card::random(40, 8, 20)),
+                PackKind::Arcana => {
+                    // Omen Globe sneaks spectral cards into Arcana packs
+                    let name = if player.vouchers.contains(&Voucher::OmenGlobe) && rng.gen_range(0..5) == 0 { random_spectral() } else { random_tarot() };
+                    PackChoice::Consumable(ConsumableFactory::create_consumable(name))
+                },
+                PackKind::Celestial => {
+                    let (planet, _) = PLANETS.choose(&mut rng).unwrap();
+                    PackChoice::Consumable(ConsumableFactory::create_consumable(planet))
+                },
+                PackKind::Buffoon => PackChoice::Joker(Joker::new(JokerFactory::random_joker(Rarity::random(), &player.jokers))),
+                PackKind::Spectral => PackChoice::Consumable(ConsumableFactory::create_consumable(random_spectral())),
+            })
+            .collect();
+
+        // Telescope guarantees the planet for the most played hand
+        if self.kind == PackKind::Celestial && player.vouchers.contains(&Voucher::Telescope) {
+            if let Some(hand) = player.hand_levels.most_played() {
+                choices[0] = PackChoice::Consumable(ConsumableFactory::create_consumable(planet_for_hand(&hand)));
+            }
+        }
+        choices
+    }
+}
+
+impl std::fmt::Display for BoosterPack {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let size = match self.size {
+            PackSize::Normal => "",
+            PackSize::Jumbo => "Jumbo ",
+            PackSize::Mega => "Mega ",
+        };
+        let kind = match self.kind {
+            PackKind::Standard => "Standard".white(),
+            PackKind::Arcana => "Arcana".purple(),
+            PackKind::Celestial => "Celestial".cyan(),
+            PackKind::Buffoon => "Buffoon".red(),
+            PackKind::Spectral => "Spectral".blue(),
+        };
+        write!(f, "{}{} Pack", size, kind)
+    }
+}
+
+// A random playing card which may come enhanced, with an edition, or sealed
@@ -1,6 +1,21 @@
 use colored::*;
+use rand::seq::SliceRandom;
+use rand::Rng;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicU64, Ordering};
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Debug, PartialEq)]
+pub struct ParseCardError(String);
+
+impl std::fmt::Display for ParseCardError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid card notation: {}", self.0)
+    }
+}
+
+impl std::error::Error for ParseCardError {}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub enum Suit {
     Spades,
     Hearts,
@@ -19,14 +34,359 @@ impl std::fmt::Display for Suit {
     }
 }
 
+impl Suit {
+    pub const ALL: [Suit; 4] = [Suit::Spades, Suit::Hearts, Suit::Clubs, Suit::Diamonds];
+
+    // Single letter used in compact card notation (e.g. the "h" in "10h")
+    pub fn letter(&self) -> char {
+        match self {
+            Suit::Spades => 's',
+            Suit::Hearts => 'h',
+            Suit::Clubs => 'c',
+            Suit::Diamonds => 'd',
+        }
+    }
+}
+
+impl FromStr for Suit {
+    type Err = ParseCardError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "s" | "S" | "♠" => Ok(Suit::Spades),
+            "h" | "H" | "♥" => Ok(Suit::Hearts),
+            "c" | "C" | "♣" => Ok(Suit::Clubs),
+            "d" | "D" | "♦" => Ok(Suit::Diamonds),
+            _ => Err(ParseCardError(format!("unknown suit '{}'", s))),
+        }
+    }
+}
+
+// Ranks are ordered from Two up to Ace, so Ace is high unless explicitly treated as low
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Rank {
+    Two,
+    Three,
+    Four,
+    Five,
+    Six,
+    Seven,
+    Eight,
+    Nine,
+    Ten,
+    Jack,
+    Queen,
+    King,
+    Ace,
+}
+
+impl Rank {
+    pub const ALL: [Rank; 13] = [
+        Rank::Two, Rank::Three, Rank::Four, Rank::Five, Rank::Six, Rank::Seven, Rank::Eight,
+        Rank::Nine, Rank::Ten, Rank::Jack, Rank::Queen, Rank::King, Rank::Ace,
+    ];
+
+    // Numeric value with Ace high (2-14)
+    pub fn value(&self) -> u8 {
+        *self as u8 + 2
+    }
+
+    // Numeric value with Ace low (1-13), used for A-2-3-4-5 straights
+    pub fn low_value(&self) -> u8 {
+        match self {
+            Rank::Ace => 1,
+            _ => self.value(),
+        }
+    }
+
+    // Chips the card is worth when scored
+    pub fn chips(&self) -> u64 {
+        match self {
+            Rank::Ace => 11,
+            Rank::Jack | Rank::Queen | Rank::King => 10,
+            _ => self.value() as u64,
+        }
+    }
+
+    // The rank one higher, wrapping from Ace back around to Two
+    pub fn next(&self) -> Rank {
+        Rank::ALL[(*self as usize + 1) % Rank::ALL.len()]
+    }
+
+    pub fn is_face(&self) -> bool {
+        matches!(self, Rank::Jack | Rank::Queen | Rank::King)
+    }
+
+    fn symbol(&self) -> &'static str {
+        match self {
+            Rank::Two => "2",
+            Rank::Three => "3",
+            Rank::Four => "4",
+            Rank::Five => "5",
+            Rank::Six => "6",
+            Rank::Seven => "7",
+            Rank::Eight => "8",
+            Rank::Nine => "9",
+            Rank::Ten => "10",
+            Rank::Jack => "J",
+            Rank::Queen => "Q",
+            Rank::King => "K",
+            Rank::Ace => "A",
+        }
+    }
+}
+
+impl std::fmt::Display for Rank {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.pad(self.symbol())  // pad so that hands line up, e.g. " 9♠" and "10♠"
+    }
+}
+
+impl FromStr for Rank {
+    type Err = ParseCardError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Rank::ALL.iter()
+            .find(|rank| rank.symbol().eq_ignore_ascii_case(s))
+            .copied()
+            .ok_or_else(|| ParseCardError(format!("unknown rank '{}'", s)))
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Enhancement {
+    Bonus,  // +30 chips
+    Mult,  // +4 mult
+    Wild,  // counts as every suit
+    Glass,  // x2 mult, 1 in 4 chance to shatter
+    Steel,  // x1.5 mult while held in hand
+    Stone,  // no rank or suit, +50 chips, always scores
+    Gold,  // $3 at end of round while held in hand
+    Lucky,  // 1 in 5 chance for +20 mult, 1 in 15 chance for $20
+}
+
+impl Enhancement {
+    pub const ALL: [Enhancement; 8] = [
+        Enhancement::Bonus, Enhancement::Mult, Enhancement::Wild, Enhancement::Glass,
+        Enhancement::Steel, Enhancement::Stone, Enhancement::Gold, Enhancement::Lucky,
+    ];
+}
+
+impl std::fmt::Display for Enhancement {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Enhancement::Bonus => write!(f, "{}", "Bonus".cyan()),
+            Enhancement::Mult => write!(f, "{}", "Mult".red()),
+            Enhancement::Wild => write!(f, "{}", "Wild".magenta()),
+            Enhancement::Glass => write!(f, "{}", "Glass".bright_white()),
+            Enhancement::Steel => write!(f, "{}", "Steel".white()),
+            Enhancement::Stone => write!(f, "{}", "Stone".bright_black()),
+            Enhancement::Gold => write!(f, "{}", "Gold".yellow()),
+            Enhancement::Lucky => write!(f, "{}", "Lucky".green()),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Edition {
+    Foil,  // +50 chips
+    Holographic,  // +10 mult
+    Polychrome,  // x1.5 mult
+    Negative,  // +1 joker slot; only applies to jokers
+}
+
+impl Edition {
+    // Roll one of the scoring editions (Negative is never rolled here)
+    pub fn random() -> Edition {
+        let mut rng = rand::thread_rng();
+        match rng.gen_range(0..100) {
+            0..=49 => Edition::Foil,
+            50..=84 => Edition::Holographic,
+            _ => Edition::Polychrome,
+        }
+    }
+
+    pub fn description(&self) -> String {
+        match self {
+            Edition::Foil => format!("{} {}", "+50".cyan().bold(), "Chips".bold()),
+            Edition::Holographic => format!("{} {}", "+10".red().bold(), "Mult".bold()),
+            Edition::Polychrome => format!("{} {}", "x1.5".red().bold(), "Mult".bold()),
+            Edition::Negative => format!("{} Joker slot", "+1".bold()),
+        }
+    }
+}
+
+impl std::fmt::Display for Edition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Edition::Foil => write!(f, "{}", "Foil".bright_cyan()),
+            Edition::Holographic => write!(f, "{}", "Holographic".bright_red()),
+            Edition::Polychrome => write!(f, "{}", "Polychrome".bright_magenta()),
+            Edition::Negative => write!(f, "{}", "Negative".reversed()),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Seal {
+    Gold,  // $3 when the card scores
+    Red,  // retrigger the card
+    Blue,  // creates a planet card if held in hand at end of round
+    Purple,  // creates a tarot card when discarded
+}
+
+impl Seal {
+    pub const ALL: [Seal; 4] = [Seal::Gold, Seal::Red, Seal::Blue, Seal::Purple];
+}
+
+impl std::fmt::Display for Seal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Seal::Gold => write!(f, "{}", "Gold Seal".yellow()),
+            Seal::Red => write!(f, "{}", "Red Seal".red()),
+            Seal::Blue => write!(f, "{}", "Blue Seal".blue()),
+            Seal::Purple => write!(f, "{}", "Purple Seal".purple()),
+        }
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct Card {
     pub suit: Suit,
-    pub rank: String,  // 2, 3, 4, 5, 6, 7, 8, 9, 10, J, Q, K, A
+    pub rank: Rank,
+    pub enhancement: Option<Enhancement>,
+    pub edition: Option<Edition>,
+    pub seal: Option<Seal>,
+    pub face_down: bool,  // only while in hand, set by some boss blinds
+    pub id: u64,  // tells identical copies apart (e.g. from Cryptid); not part of equality
+}
+
+static NEXT_CARD_ID: AtomicU64 = AtomicU64::new(0);
+
+fn next_card_id() -> u64 {
+    NEXT_CARD_ID.fetch_add(1, Ordering::Relaxed)
+}
+
+// Being face-down isn't part of a card's identity, so a card in hand still matches its copy in the deck
+impl PartialEq for Card {
+    fn eq(&self, other: &Self) -> bool {
+        self.suit == other.suit
+            && self.rank == other.rank
+            && self.enhancement == other.enhancement
+            && self.edition == other.edition
+            && self.seal == other.seal
+    }
+}
+
+impl Card {
+    pub fn new(suit: Suit, rank: Rank) -> Self {
+        Self {
+            suit,
+            rank,
+            enhancement: None,
+            edition: None,
+            seal: None,
+            face_down: false,
+            id: next_card_id(),
+        }
+    }
+
+    // A new card identical to this one, but counted as a separate card
+    pub fn duplicate(&self) -> Self {
+        Self { id: next_card_id(), ..self.clone() }
+    }
+
+    // A random card, with the given percent chances of an enhancement, an edition and a seal
+    pub fn random(enhancement_chance: u32, edition_chance: u32, seal_chance: u32) -> Self {
+        let mut rng = rand::thread_rng();
+        let mut card = Card::new(Suit::ALL.choose(&mut rng).unwrap().clone(), *Rank::ALL.choose(&mut rng).unwrap());
+        if rng.gen_range(0..100) < enhancement_chance {
+            card.enhancement = Enhancement::ALL.choose(&mut rng).copied();
+        }
+        if rng.gen_range(0..100) < edition_chance {
+            card.edition = Some(Edition::random());
+        }
+        if rng.gen_range(0..100) < seal_chance {
+            card.seal = Seal::ALL.choose(&mut rng).copied();
+        }
+        card
+    }
+
+    pub fn is_stone(&self) -> bool {
+        self.enhancement == Some(Enhancement::Stone)
+    }
+
+    // Wild cards count as every suit, while Stone cards have no suit at all
+    pub fn is_suit(&self, suit: &Suit) -> bool {
+        match self.enhancement {
+            Some(Enhancement::Stone) => false,
+            Some(Enhancement::Wild) => true,
+            _ => self.suit == *suit,
+        }
+    }
 }
 
+// The alternate form (`{:#}`) writes compact notation such as "10h" or "As", which `Card::from_str` reads back
 impl std::fmt::Display for Card {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{:>2}{}", self.rank, self.suit)
+        if f.alternate() {
+            return write!(f, "{}{}", self.rank, self.suit.letter());
+        }
+        match self.enhancement {
+            Some(Enhancement::Stone) => write!(f, "{}", Enhancement::Stone)?,  // rank and suit are hidden
+            Some(enhancement) => write!(f, "{:>2}{} ({})", self.rank, self.suit, enhancement)?,
+            None => write!(f, "{:>2}{}", self.rank, self.suit)?,
+        }
+        if let Some(edition) = self.edition {
+            write!(f, " [{}]", edition)?;
+        }
+        if let Some(seal) = self.seal {
+            write!(f, " [{}]", seal)?;
+        }
+        Ok(())
     }
-}
\ No newline at end of file
+}
+
+// Parses compact notation: a rank followed by a suit letter, e.g. "10h", "As" or "Kd"
+impl FromStr for Card {
+    type Err = ParseCardError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let (split, _) = s.char_indices().last()
+            .ok_or_else(|| ParseCardError("empty string".to_string()))?;
+        let rank = s[..split].parse::<Rank>()?;
+        let suit = s[split..].parse::<Suit>()?;
+        Ok(Card::new(suit, rank))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compact_notation_round_trips() {
+        for suit in Suit::ALL {
+            for rank in Rank::ALL {
+                let card = Card::new(suit.clone(), rank);
+                let notation = format!("{:#}", card);
+                assert_eq!(notation.parse::<Card>(), Ok(card), "{}", notation);
+            }
+        }
+    }
+
+    #[test]
+    fn parses_compact_notation() {
+        assert_eq!("10h".parse::<Card>(), Ok(Card::new(Suit::Hearts, Rank::Ten)));
+        assert_eq!("As".parse::<Card>(), Ok(Card::new(Suit::Spades, Rank::Ace)));
+        assert_eq!(" kD ".parse::<Card>(), Ok(Card::new(Suit::Diamonds, Rank::King)));
+    }
+
+    #[test]
+    fn rejects_invalid_notation() {
+        for notation in ["", "h", "1h", "Xs", "10x", "10"] {
+            assert!(notation.parse::<Card>().is_err(), "{:?} should not parse", notation);
+        }
+    }
+}
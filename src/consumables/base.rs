@@ -0,0 +1,104 @@
+use colored::*;
+
+use crate::Player;
+use crate::cards::{Enhancement, Rank, Seal, Suit};
+use crate::consumables::*;
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum ConsumableKind {
+    Planet,
+    Tarot,
+    Spectral,
+}
+
+impl ConsumableKind {
+    // Price in the shop
+    pub fn cost(&self) -> i32 {
+        match self {
+            ConsumableKind::Planet | ConsumableKind::Tarot => 3,
+            ConsumableKind::Spectral => 4,
+        }
+    }
+}
+
+impl std::fmt::Display for ConsumableKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConsumableKind::Planet => write!(f, "{}", "Planet".cyan()),
+            ConsumableKind::Tarot => write!(f, "{}", "Tarot".purple()),
+            ConsumableKind::Spectral => write!(f, "{}", "Spectral".blue()),
+        }
+    }
+}
+
+pub trait ConsumableAbility {
+    fn name(&self) -> &str;
+    fn description(&self) -> String;
+    fn kind(&self) -> ConsumableKind;
+
+    // Minimum and maximum number of cards in hand that must be selected to use this consumable
+    fn selection_range(&self) -> (usize, usize) {
+        (0, 0)
+    }
+
+    // Whether the consumable can currently be used (e.g. there's room for what it creates)
+    fn can_use(&self, _player: &Player) -> bool {
+        true
+    }
+
+    // Apply the consumable's effect; `selected` are indices into the player's hand
+    fn use_consumable(&self, player: &mut Player, selected: &[usize]);
+}
+
+pub struct ConsumableFactory {}
+
+impl ConsumableFactory {
+    pub fn create_consumable(name: &str) -> Box<dyn ConsumableAbility> {
+        if let Some(&(planet_name, hand)) = PLANETS.iter().find(|(planet_name, _)| *planet_name == name) {
+            return Box::new(Planet::new(planet_name, hand));
+        }
+        match name {
+            "The Fool" => Box::new(TheFool {}),
+            "The Magician" => Box::new(EnhancementTarot::new("The Magician", Enhancement::Lucky, 2)),
+            "The High Priestess" => Box::new(TheHighPriestess {}),
+            "The Empress" => Box::new(EnhancementTarot::new("The Empress", Enhancement::Mult, 2)),
+            "The Emperor" => Box::new(TheEmperor {}),
+            "The Hierophant" => Box::new(EnhancementTarot::new("The Hierophant", Enhancement::Bonus, 2)),
+            "The Lovers" => Box::new(EnhancementTarot::new("The Lovers", Enhancement::Wild, 1)),
+            "The Chariot" => Box::new(EnhancementTarot::new("The Chariot", Enhancement::Steel, 1)),
+            "Justice" => Box::new(EnhancementTarot::new("Justice", Enhancement::Glass, 1)),
+            "The Hermit" => Box::new(TheHermit {}),
+            "The Wheel of Fortune" => Box::new(TheWheelOfFortune {}),
+            "Strength" => Box::new(Strength {}),
+            "The Hanged Man" => Box::new(TheHangedMan {}),
+            "Death" => Box::new(Death {}),
+            "Temperance" => Box::new(Temperance {}),
+            "The Devil" => Box::new(EnhancementTarot::new("The Devil", Enhancement::Gold, 1)),
+            "The Tower" => Box::new(EnhancementTarot::new("The Tower", Enhancement::Stone, 1)),
+            "The Star" => Box::new(SuitTarot::new("The Star", Suit::Diamonds)),
+            "The Moon" => Box::new(SuitTarot::new("The Moon", Suit::Clubs)),
+            "The Sun" => Box::new(SuitTarot::new("The Sun", Suit::Hearts)),
+            "Judgement" => Box::new(Judgement {}),
+            "The World" => Box::new(SuitTarot::new("The World", Suit::Spades)),
+            "Familiar" => Box::new(ConjureSpectral::new("Familiar", &[Rank::Jack, Rank::Queen, Rank::King], "face cards", 3)),
+            "Grim" => Box::new(ConjureSpectral::new("Grim", &[Rank::Ace], "Aces", 2)),
+            "Incantation" => Box::new(ConjureSpectral::new("Incantation", &Rank::ALL[..9], "numbered cards", 4)),
+            "Talisman" => Box::new(SealSpectral::new("Talisman", Seal::Gold)),
+            "Aura" => Box::new(Aura {}),
+            "Wraith" => Box::new(Wraith {}),
+            "Sigil" => Box::new(Sigil {}),
+            "Ouija" => Box::new(Ouija {}),
+            "Ectoplasm" => Box::new(Ectoplasm {}),
+            "Immolate" => Box::new(Immolate {}),
+            "Ankh" => Box::new(Ankh {}),
+            "Deja Vu" => Box::new(SealSpectral::new("Deja Vu", Seal::Red)),
+            "Hex" => Box::new(Hex {}),
+            "Trance" => Box::new(SealSpectral::new("Trance", Seal::Blue)),
+            "Medium" => Box::new(SealSpectral::new("Medium", Seal::Purple)),
+            "Cryptid" => Box::new(Cryptid {}),
+            "The Soul" => Box::new(TheSoul {}),
+            "Black Hole" => Box::new(BlackHole {}),
+            _ => panic!("Unknown consumable: {}", name),
+        }
+    }
+}
@@ -0,0 +1,9 @@
+mod base;
+mod planets;
+mod spectrals;
+mod tarots;
+
+pub use base::{ConsumableAbility, ConsumableFactory, ConsumableKind};
+pub use planets::*;
+pub use spectrals::*;
+pub use tarots::*;
@@ -0,0 +1,68 @@
+use colored::*;
+
+use crate::Player;
+use crate::PokerHand;
+use crate::consumables::base::{ConsumableAbility, ConsumableKind};
+use crate::pause_after_print;
+
+// Every planet card and the poker hand it levels up
+pub const PLANETS: [(&str, PokerHand); 12] = [
+    ("Pluto", PokerHand::HighCard),
+    ("Mercury", PokerHand::Pair),
+    ("Uranus", PokerHand::TwoPair),
+    ("Venus", PokerHand::ThreeOfAKind),
+    ("Saturn", PokerHand::Straight),
+    ("Jupiter", PokerHand::Flush),
+    ("Earth", PokerHand::FullHouse),
+    ("Mars", PokerHand::FourOfAKind),
+    ("Neptune", PokerHand::StraightFlush),
+    ("Planet X", PokerHand::FiveOfAKind),
+    ("Ceres", PokerHand::FlushHouse),
+    ("Eris", PokerHand::FlushFive),
+];
+
+pub fn planet_for_hand(hand: &PokerHand) -> &'static str {
+    PLANETS.iter()
+        .find(|(_, planet_hand)| planet_hand == hand)
+        .map(|(name, _)| *name)
+        .unwrap()
+}
+
+pub struct Planet {
+    name: &'static str,
+    hand: PokerHand,
+}
+
+impl Planet {
+    pub fn new(name: &'static str, hand: PokerHand) -> Self {
+        Self { name, hand }
+    }
+}
+
+impl ConsumableAbility for Planet {
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn description(&self) -> String {
+        let (chips, mult) = self.hand.level_increment();
+        format!(
+            "Level up {}: {} {} and {} {}",
+            self.hand.to_string().bold(),
+            format!("+{}", mult).red().bold(),
+            "Mult".bold(),
+            format!("+{}", chips).cyan().bold(),
+            "Chips".bold(),
+        )
+    }
+
+    fn kind(&self) -> ConsumableKind {
+        ConsumableKind::Planet
+    }
+
+    fn use_consumable(&self, player: &mut Player, _selected: &[usize]) {
+        player.hand_levels.level_up(&self.hand, 1);
+        println!("{}: {} leveled up to lvl.{}", self.name(), self.hand, player.hand_levels.get(&self.hand).level);
+        pause_after_print(1000);
+    }
+}
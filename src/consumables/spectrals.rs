@@ -0,0 +1,476 @@
+use colored::*;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use crate::Player;
+use crate::PokerHand;
+use crate::cards::{Card, Edition, Enhancement, Rank, Seal, Suit};
+use crate::consumables::base::{ConsumableAbility, ConsumableKind};
+use crate::jokers::{Joker, JokerFactory, Rarity};
+use crate::pause_after_print;
+
+// Every spectral card
+pub const SPECTRALS: [&str; 18] = [
+    "Familiar",
+    "Grim",
+    "Incantation",
+    "Talisman",
+    "Aura",
+    "Wraith",
+    "Sigil",
+    "Ouija",
+    "Ectoplasm",
+    "Immolate",
+    "Ankh",
+    "Deja Vu",
+    "Hex",
+    "Trance",
+    "Medium",
+    "Cryptid",
+    "The Soul",
+    "Black Hole",
+];
+
+pub fn random_spectral() -> &'static str {
+    let mut rng = rand::thread_rng();
+    SPECTRALS.choose(&mut rng).unwrap()
+}
+
+// Destroy a random card in hand; returns whether there was one to destroy
+fn destroy_random_card(player: &mut Player, source: &str) -> bool {
+    let mut rng = rand::thread_rng();
+    if player.cards_in_hand.is_empty() {
+        return false;
+    }
+    let idx = rng.gen_range(0..player.cards_in_hand.len());
+    println!("{}: {} destroyed", source, player.cards_in_hand[idx]);
+    pause_after_print(400);
+    player.destroy_cards_in_hand(&[idx]);
+    true
+}
+
+// Spectrals that destroy a random card in hand and add enhanced cards of certain ranks (Familiar, Grim, Incantation)
+pub struct ConjureSpectral {
+    name: &'static str,
+    ranks: &'static [Rank],
+    rank_description: &'static str,
+    count: usize,
+}
+
+impl ConjureSpectral {
+    pub fn new(name: &'static str, ranks: &'static [Rank], rank_description: &'static str, count: usize) -> Self {
+        Self { name, ranks, rank_description, count }
+    }
+}
+
+impl ConsumableAbility for ConjureSpectral {
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn description(&self) -> String {
+        format!(
+            "Destroy 1 random card in your hand, add {} random {} {} to your hand",
+            self.count,
+            "Enhanced".bold(),
+            self.rank_description,
+        )
+    }
+
+    fn kind(&self) -> ConsumableKind {
+        ConsumableKind::Spectral
+    }
+
+    fn can_use(&self, player: &Player) -> bool {
+        !player.cards_in_hand.is_empty()
+    }
+
+    fn use_consumable(&self, player: &mut Player, _selected: &[usize]) {
+        let mut rng = rand::thread_rng();
+        destroy_random_card(player, self.name());
+        // Stone cards would hide the rank, so they're never conjured
+        let enhancements: Vec<Enhancement> = Enhancement::ALL.iter()
+            .copied()
+            .filter(|&enhancement| enhancement != Enhancement::Stone)
+            .collect();
+        for _ in 0..self.count {
+            let mut card = Card::new(
+                Suit::ALL.choose(&mut rng).unwrap().clone(),
+                *self.ranks.choose(&mut rng).unwrap(),
+            );
+            card.enhancement = enhancements.choose(&mut rng).copied();
+            println!("{}: {} added", self.name(), card);
+            pause_after_print(400);
+            player.add_card_to_hand(card);
+        }
+    }
+}
+
+// Spectrals that add a seal to the selected card (Talisman, Deja Vu, Trance, Medium)
+pub struct SealSpectral {
+    name: &'static str,
+    seal: Seal,
+}
+
+impl SealSpectral {
+    pub fn new(name: &'static str, seal: Seal) -> Self {
+        Self { name, seal }
+    }
+}
+
+impl ConsumableAbility for SealSpectral {
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn description(&self) -> String {
+        format!("Add a {} to 1 selected card in your hand", self.seal)
+    }
+
+    fn kind(&self) -> ConsumableKind {
+        ConsumableKind::Spectral
+    }
+
+    fn selection_range(&self) -> (usize, usize) {
+        (1, 1)
+    }
+
+    fn use_consumable(&self, player: &mut Player, selected: &[usize]) {
+        let idx = selected[0];
+        player.modify_card_in_hand(idx, |card| card.seal = Some(self.seal));
+        println!("{}: {}", self.name(), player.cards_in_hand[idx]);
+        pause_after_print(400);
+    }
+}
+
+pub struct Aura {}
+
+impl ConsumableAbility for Aura {
+    fn name(&self) -> &str {
+        "Aura"
+    }
+
+    fn description(&self) -> String {
+        format!(
+            "Add {}, {} or {} effect to 1 selected card in hand",
+            Edition::Foil,
+            Edition::Holographic,
+            Edition::Polychrome,
+        )
+    }
+
+    fn kind(&self) -> ConsumableKind {
+        ConsumableKind::Spectral
+    }
+
+    fn selection_range(&self) -> (usize, usize) {
+        (1, 1)
+    }
+
+    fn use_consumable(&self, player: &mut Player, selected: &[usize]) {
+        let idx = selected[0];
+        player.modify_card_in_hand(idx, |card| card.edition = Some(Edition::random()));
+        println!("{}: {}", self.name(), player.cards_in_hand[idx]);
+        pause_after_print(400);
+    }
+}
+
+pub struct Wraith {}
+
+impl ConsumableAbility for Wraith {
+    fn name(&self) -> &str {
+        "Wraith"
+    }
+
+    fn description(&self) -> String {
+        format!("Creates a random {} Joker (Must have room), sets money to {}", Rarity::Rare, "$0".yellow())
+    }
+
+    fn kind(&self) -> ConsumableKind {
+        ConsumableKind::Spectral
+    }
+
+    fn can_use(&self, player: &Player) -> bool {
+        player.has_joker_slot()
+    }
+
+    fn use_consumable(&self, player: &mut Player, _selected: &[usize]) {
+        let joker = Joker::new(JokerFactory::random_joker(Rarity::Rare, &player.jokers));
+        println!("{}: {} created ({})", self.name(), joker.name(), joker.description());
+        player.add_joker(joker);
+        player.money = 0;
+        println!("{}: money set to {}", self.name(), "$0".yellow());
+        pause_after_print(400);
+    }
+}
+
+pub struct Sigil {}
+
+impl ConsumableAbility for Sigil {
+    fn name(&self) -> &str {
+        "Sigil"
+    }
+
+    fn description(&self) -> String {
+        "Converts all cards in hand to a single random suit".to_string()
+    }
+
+    fn kind(&self) -> ConsumableKind {
+        ConsumableKind::Spectral
+    }
+
+    fn can_use(&self, player: &Player) -> bool {
+        !player.cards_in_hand.is_empty()
+    }
+
+    fn use_consumable(&self, player: &mut Player, _selected: &[usize]) {
+        let mut rng = rand::thread_rng();
+        let suit = Suit::ALL.choose(&mut rng).unwrap().clone();
+        for idx in 0..player.cards_in_hand.len() {
+            player.modify_card_in_hand(idx, |card| card.suit = suit.clone());
+        }
+        println!("{}: all cards in hand converted to {}", self.name(), suit);
+        pause_after_print(400);
+    }
+}
+
+pub struct Ouija {}
+
+impl ConsumableAbility for Ouija {
+    fn name(&self) -> &str {
+        "Ouija"
+    }
+
+    fn description(&self) -> String {
+        format!("Converts all cards in hand to a single random rank, {} hand size", "-1".red())
+    }
+
+    fn kind(&self) -> ConsumableKind {
+        ConsumableKind::Spectral
+    }
+
+    fn can_use(&self, player: &Player) -> bool {
+        !player.cards_in_hand.is_empty()
+    }
+
+    fn use_consumable(&self, player: &mut Player, _selected: &[usize]) {
+        let mut rng = rand::thread_rng();
+        let rank = *Rank::ALL.choose(&mut rng).unwrap();
+        for idx in 0..player.cards_in_hand.len() {
+            player.modify_card_in_hand(idx, |card| card.rank = rank);
+        }
+        println!("{}: all cards in hand converted to {}", self.name(), rank);
+        pause_after_print(400);
+        player.change_hand_size(-1);
+    }
+}
+
+pub struct Ectoplasm {}
+
+impl ConsumableAbility for Ectoplasm {
+    fn name(&self) -> &str {
+        "Ectoplasm"
+    }
+
+    fn description(&self) -> String {
+        format!("Add {} to a random Joker, {} hand size", Edition::Negative, "-1".red())
+    }
+
+    fn kind(&self) -> ConsumableKind {
+        ConsumableKind::Spectral
+    }
+
+    fn can_use(&self, player: &Player) -> bool {
+        player.jokers.iter().any(|joker| joker.edition.is_none())
+    }
+
+    fn use_consumable(&self, player: &mut Player, _selected: &[usize]) {
+        let mut rng = rand::thread_rng();
+        let candidates: Vec<usize> = (0..player.jokers.len())
+            .filter(|&i| player.jokers[i].edition.is_none())
+            .collect();
+        if let Some(&idx) = candidates.choose(&mut rng) {
+            player.jokers[idx].edition = Some(Edition::Negative);
+            println!("{}: {} is now {}", self.name(), player.jokers[idx].name(), Edition::Negative);
+            pause_after_print(400);
+        }
+        player.change_hand_size(-1);
+    }
+}
+
+pub struct Immolate {}
+
+impl ConsumableAbility for Immolate {
+    fn name(&self) -> &str {
+        "Immolate"
+    }
+
+    fn description(&self) -> String {
+        format!("Destroys 5 random cards in hand, gain {}", "$20".yellow())
+    }
+
+    fn kind(&self) -> ConsumableKind {
+        ConsumableKind::Spectral
+    }
+
+    fn can_use(&self, player: &Player) -> bool {
+        !player.cards_in_hand.is_empty()
+    }
+
+    fn use_consumable(&self, player: &mut Player, _selected: &[usize]) {
+        for _ in 0..5 {
+            if !destroy_random_card(player, self.name()) {
+                break;
+            }
+        }
+        player.money += 20;
+        println!("{}: {}", self.name(), "+$20".yellow());
+        pause_after_print(400);
+    }
+}
+
+pub struct Ankh {}
+
+impl ConsumableAbility for Ankh {
+    fn name(&self) -> &str {
+        "Ankh"
+    }
+
+    fn description(&self) -> String {
+        "Create a copy of a random Joker, destroy all other Jokers".to_string()
+    }
+
+    fn kind(&self) -> ConsumableKind {
+        ConsumableKind::Spectral
+    }
+
+    fn can_use(&self, player: &Player) -> bool {
+        !player.jokers.is_empty()
+    }
+
+    fn use_consumable(&self, player: &mut Player, _selected: &[usize]) {
+        let mut rng = rand::thread_rng();
+        let idx = rng.gen_range(0..player.jokers.len());
+
+        // The copy keeps the joker's counters, but never a Negative edition
+        let mut copy = player.jokers[idx].clone();
+        copy.edition = copy.edition.filter(|&edition| edition != Edition::Negative);
+        player.destroy_other_jokers(idx);
+        println!("{}: {} copied", self.name(), copy.name());
+        pause_after_print(400);
+        player.add_joker(copy);
+    }
+}
+
+pub struct Hex {}
+
+impl ConsumableAbility for Hex {
+    fn name(&self) -> &str {
+        "Hex"
+    }
+
+    fn description(&self) -> String {
+        format!("Add {} to a random Joker, destroy all other Jokers", Edition::Polychrome)
+    }
+
+    fn kind(&self) -> ConsumableKind {
+        ConsumableKind::Spectral
+    }
+
+    fn can_use(&self, player: &Player) -> bool {
+        player.jokers.iter().any(|joker| joker.edition.is_none())
+    }
+
+    fn use_consumable(&self, player: &mut Player, _selected: &[usize]) {
+        let mut rng = rand::thread_rng();
+        let candidates: Vec<usize> = (0..player.jokers.len())
+            .filter(|&i| player.jokers[i].edition.is_none())
+            .collect();
+        if let Some(&idx) = candidates.choose(&mut rng) {
+            player.jokers[idx].edition = Some(Edition::Polychrome);
+            println!("{}: {} is now {}", self.name(), player.jokers[idx].name(), Edition::Polychrome);
+            pause_after_print(400);
+            player.destroy_other_jokers(idx);
+        }
+    }
+}
+
+pub struct Cryptid {}
+
+impl ConsumableAbility for Cryptid {
+    fn name(&self) -> &str {
+        "Cryptid"
+    }
+
+    fn description(&self) -> String {
+        "Create 2 copies of 1 selected card in your hand".to_string()
+    }
+
+    fn kind(&self) -> ConsumableKind {
+        ConsumableKind::Spectral
+    }
+
+    fn selection_range(&self) -> (usize, usize) {
+        (1, 1)
+    }
+
+    fn use_consumable(&self, player: &mut Player, selected: &[usize]) {
+        let card = player.cards_in_hand[selected[0]].clone();
+        for _ in 0..2 {
+            player.add_card_to_hand(card.duplicate());
+        }
+        println!("{}: 2 copies of {} added", self.name(), card);
+        pause_after_print(400);
+    }
+}
+
+pub struct TheSoul {}
+
+impl ConsumableAbility for TheSoul {
+    fn name(&self) -> &str {
+        "The Soul"
+    }
+
+    fn description(&self) -> String {
+        format!("Creates a {} Joker (Must have room)", Rarity::Legendary)
+    }
+
+    fn kind(&self) -> ConsumableKind {
+        ConsumableKind::Spectral
+    }
+
+    fn can_use(&self, player: &Player) -> bool {
+        player.has_joker_slot()
+    }
+
+    fn use_consumable(&self, player: &mut Player, _selected: &[usize]) {
+        let joker = Joker::new(JokerFactory::random_joker(Rarity::Legendary, &player.jokers));
+        println!("{}: {} created ({})", self.name(), joker.name(), joker.description());
+        player.add_joker(joker);
+        pause_after_print(400);
+    }
+}
+
+pub struct BlackHole {}
+
+impl ConsumableAbility for BlackHole {
+    fn name(&self) -> &str {
+        "Black Hole"
+    }
+
+    fn description(&self) -> String {
+        "Upgrade every poker hand by 1 level".to_string()
+    }
+
+    fn kind(&self) -> ConsumableKind {
+        ConsumableKind::Spectral
+    }
+
+    fn use_consumable(&self, player: &mut Player, _selected: &[usize]) {
+        for hand in PokerHand::ALL.iter() {
+            player.hand_levels.level_up(hand, 1);
+        }
+        println!("{}: every poker hand leveled up", self.name());
+        pause_after_print(1000);
+    }
+}
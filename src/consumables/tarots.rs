@@ -0,0 +1,405 @@
+use colored::*;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use crate::Player;
+use crate::cards::{Card, Edition, Enhancement, Suit};
+use crate::consumables::base::{ConsumableAbility, ConsumableKind};
+use crate::consumables::planets::PLANETS;
+use crate::jokers::{Joker, JokerFactory, Rarity};
+use crate::pause_after_print;
+
+// Every tarot card, in the order of the Major Arcana
+pub const TAROTS: [&str; 22] = [
+    "The Fool",
+    "The Magician",
+    "The High Priestess",
+    "The Empress",
+    "The Emperor",
+    "The Hierophant",
+    "The Lovers",
+    "The Chariot",
+    "Justice",
+    "The Hermit",
+    "The Wheel of Fortune",
+    "Strength",
+    "The Hanged Man",
+    "Death",
+    "Temperance",
+    "The Devil",
+    "The Tower",
+    "The Star",
+    "The Moon",
+    "The Sun",
+    "Judgement",
+    "The World",
+];
+
+pub fn random_tarot() -> &'static str {
+    let mut rng = rand::thread_rng();
+    TAROTS.choose(&mut rng).unwrap()
+}
+
+// Tarots that enhance the selected cards (e.g. The Magician, The Chariot)
+pub struct EnhancementTarot {
+    name: &'static str,
+    enhancement: Enhancement,
+    max_cards: usize,
+}
+
+impl EnhancementTarot {
+    pub fn new(name: &'static str, enhancement: Enhancement, max_cards: usize) -> Self {
+        Self { name, enhancement, max_cards }
+    }
+}
+
+impl ConsumableAbility for EnhancementTarot {
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn description(&self) -> String {
+        let cards = if self.max_cards == 1 { "1 selected card".to_string() } else { format!("up to {} selected cards", self.max_cards) };
+        format!("Enhances {} into {} cards", cards, self.enhancement)
+    }
+
+    fn kind(&self) -> ConsumableKind {
+        ConsumableKind::Tarot
+    }
+
+    fn selection_range(&self) -> (usize, usize) {
+        (1, self.max_cards)
+    }
+
+    fn use_consumable(&self, player: &mut Player, selected: &[usize]) {
+        for &idx in selected {
+            player.modify_card_in_hand(idx, |card| card.enhancement = Some(self.enhancement));
+            println!("{}: {}", self.name(), player.cards_in_hand[idx]);
+            pause_after_print(400);
+        }
+    }
+}
+
+// Tarots that convert the selected cards to a suit (e.g. The Star, The World)
+pub struct SuitTarot {
+    name: &'static str,
+    suit: Suit,
+}
+
+impl SuitTarot {
+    pub fn new(name: &'static str, suit: Suit) -> Self {
+        Self { name, suit }
+    }
+}
+
+impl ConsumableAbility for SuitTarot {
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn description(&self) -> String {
+        format!("Converts up to 3 selected cards to {}", self.suit)
+    }
+
+    fn kind(&self) -> ConsumableKind {
+        ConsumableKind::Tarot
+    }
+
+    fn selection_range(&self) -> (usize, usize) {
+        (1, 3)
+    }
+
+    fn use_consumable(&self, player: &mut Player, selected: &[usize]) {
+        for &idx in selected {
+            player.modify_card_in_hand(idx, |card| card.suit = self.suit.clone());
+            println!("{}: {}", self.name(), player.cards_in_hand[idx]);
+            pause_after_print(400);
+        }
+    }
+}
+
+pub struct TheFool {}
+
+impl ConsumableAbility for TheFool {
+    fn name(&self) -> &str {
+        "The Fool"
+    }
+
+    fn description(&self) -> String {
+        "Creates the last Tarot or Planet card used during this run (The Fool excluded)".to_string()
+    }
+
+    fn kind(&self) -> ConsumableKind {
+        ConsumableKind::Tarot
+    }
+
+    fn can_use(&self, player: &Player) -> bool {
+        player.last_consumable_used.is_some()
+    }
+
+    fn use_consumable(&self, player: &mut Player, _selected: &[usize]) {
+        if let Some(name) = player.last_consumable_used.clone() {
+            if player.add_consumable(&name) {
+                println!("{}: {} created", self.name(), name);
+                pause_after_print(400);
+            }
+        }
+    }
+}
+
+pub struct TheHighPriestess {}
+
+impl ConsumableAbility for TheHighPriestess {
+    fn name(&self) -> &str {
+        "The High Priestess"
+    }
+
+    fn description(&self) -> String {
+        format!("Creates up to 2 random {} cards", ConsumableKind::Planet)
+    }
+
+    fn kind(&self) -> ConsumableKind {
+        ConsumableKind::Tarot
+    }
+
+    fn use_consumable(&self, player: &mut Player, _selected: &[usize]) {
+        let mut rng = rand::thread_rng();
+        for _ in 0..2 {
+            let (planet, _) = PLANETS.choose(&mut rng).unwrap();
+            if !player.add_consumable(planet) {
+                break;
+            }
+            println!("{}: {} created", self.name(), planet);
+            pause_after_print(400);
+        }
+    }
+}
+
+pub struct TheEmperor {}
+
+impl ConsumableAbility for TheEmperor {
+    fn name(&self) -> &str {
+        "The Emperor"
+    }
+
+    fn description(&self) -> String {
+        format!("Creates up to 2 random {} cards", ConsumableKind::Tarot)
+    }
+
+    fn kind(&self) -> ConsumableKind {
+        ConsumableKind::Tarot
+    }
+
+    fn use_consumable(&self, player: &mut Player, _selected: &[usize]) {
+        for _ in 0..2 {
+            let tarot = random_tarot();
+            if !player.add_consumable(tarot) {
+                break;
+            }
+            println!("{}: {} created", self.name(), tarot);
+            pause_after_print(400);
+        }
+    }
+}
+
+pub struct TheHermit {}
+
+impl ConsumableAbility for TheHermit {
+    fn name(&self) -> &str {
+        "The Hermit"
+    }
+
+    fn description(&self) -> String {
+        format!("Doubles money (Max of {})", "$20".yellow())
+    }
+
+    fn kind(&self) -> ConsumableKind {
+        ConsumableKind::Tarot
+    }
+
+    fn use_consumable(&self, player: &mut Player, _selected: &[usize]) {
+        let gain = player.money.clamp(0, 20);
+        player.money += gain;
+        println!("{}: {}", self.name(), format!("+${}", gain).yellow());
+        pause_after_print(400);
+    }
+}
+
+pub struct TheWheelOfFortune {}
+
+impl ConsumableAbility for TheWheelOfFortune {
+    fn name(&self) -> &str {
+        "The Wheel of Fortune"
+    }
+
+    fn description(&self) -> String {
+        format!(
+            "1 in 4 chance to add {}, {} or {} edition to a random Joker",
+            Edition::Foil,
+            Edition::Holographic,
+            Edition::Polychrome,
+        )
+    }
+
+    fn kind(&self) -> ConsumableKind {
+        ConsumableKind::Tarot
+    }
+
+    fn can_use(&self, player: &Player) -> bool {
+        player.jokers.iter().any(|joker| joker.edition.is_none())
+    }
+
+    fn use_consumable(&self, player: &mut Player, _selected: &[usize]) {
+        let mut rng = rand::thread_rng();
+        if rng.gen_range(0..4) != 0 {
+            println!("{}: Nope!", self.name());
+            pause_after_print(400);
+            return;
+        }
+        let candidates: Vec<usize> = (0..player.jokers.len())
+            .filter(|&i| player.jokers[i].edition.is_none())
+            .collect();
+        if let Some(&idx) = candidates.choose(&mut rng) {
+            let edition = Edition::random();
+            player.jokers[idx].edition = Some(edition);
+            println!("{}: {} is now {}", self.name(), player.jokers[idx].name(), edition);
+            pause_after_print(400);
+        }
+    }
+}
+
+pub struct Strength {}
+
+impl ConsumableAbility for Strength {
+    fn name(&self) -> &str {
+        "Strength"
+    }
+
+    fn description(&self) -> String {
+        "Increases rank of up to 2 selected cards by 1".to_string()
+    }
+
+    fn kind(&self) -> ConsumableKind {
+        ConsumableKind::Tarot
+    }
+
+    fn selection_range(&self) -> (usize, usize) {
+        (1, 2)
+    }
+
+    fn use_consumable(&self, player: &mut Player, selected: &[usize]) {
+        for &idx in selected {
+            player.modify_card_in_hand(idx, |card| card.rank = card.rank.next());
+            println!("{}: {}", self.name(), player.cards_in_hand[idx]);
+            pause_after_print(400);
+        }
+    }
+}
+
+pub struct TheHangedMan {}
+
+impl ConsumableAbility for TheHangedMan {
+    fn name(&self) -> &str {
+        "The Hanged Man"
+    }
+
+    fn description(&self) -> String {
+        "Destroys up to 2 selected cards".to_string()
+    }
+
+    fn kind(&self) -> ConsumableKind {
+        ConsumableKind::Tarot
+    }
+
+    fn selection_range(&self) -> (usize, usize) {
+        (1, 2)
+    }
+
+    fn use_consumable(&self, player: &mut Player, selected: &[usize]) {
+        for &idx in selected {
+            println!("{}: {} destroyed", self.name(), player.cards_in_hand[idx]);
+            pause_after_print(400);
+        }
+        player.destroy_cards_in_hand(selected);
+    }
+}
+
+pub struct Death {}
+
+impl ConsumableAbility for Death {
+    fn name(&self) -> &str {
+        "Death"
+    }
+
+    fn description(&self) -> String {
+        "Select 2 cards, convert the left card into the right card".to_string()
+    }
+
+    fn kind(&self) -> ConsumableKind {
+        ConsumableKind::Tarot
+    }
+
+    fn selection_range(&self) -> (usize, usize) {
+        (2, 2)
+    }
+
+    fn use_consumable(&self, player: &mut Player, selected: &[usize]) {
+        let (left, right) = (selected[0], selected[1]);
+        // The converted card takes on everything but the other card's identity
+        let copy = player.cards_in_hand[right].clone();
+        player.modify_card_in_hand(left, |card| *card = Card { id: card.id, ..copy });
+        println!("{}: {} converted", self.name(), player.cards_in_hand[left]);
+        pause_after_print(400);
+    }
+}
+
+pub struct Temperance {}
+
+impl ConsumableAbility for Temperance {
+    fn name(&self) -> &str {
+        "Temperance"
+    }
+
+    fn description(&self) -> String {
+        format!("Gives the total sell value of all current Jokers (Max of {})", "$50".yellow())
+    }
+
+    fn kind(&self) -> ConsumableKind {
+        ConsumableKind::Tarot
+    }
+
+    fn use_consumable(&self, player: &mut Player, _selected: &[usize]) {
+        let total: i32 = player.jokers.iter().map(|joker| joker.sell_value()).sum();
+        let gain = std::cmp::min(total, 50);
+        player.money += gain;
+        println!("{}: {}", self.name(), format!("+${}", gain).yellow());
+        pause_after_print(400);
+    }
+}
+
+pub struct Judgement {}
+
+impl ConsumableAbility for Judgement {
+    fn name(&self) -> &str {
+        "Judgement"
+    }
+
+    fn description(&self) -> String {
+        "Creates a random Joker card (Must have room)".to_string()
+    }
+
+    fn kind(&self) -> ConsumableKind {
+        ConsumableKind::Tarot
+    }
+
+    fn can_use(&self, player: &Player) -> bool {
+        player.has_joker_slot()
+    }
+
+    fn use_consumable(&self, player: &mut Player, _selected: &[usize]) {
+        let joker = Joker::new(JokerFactory::random_joker(Rarity::random(), &player.jokers));
+        println!("{}: {} created ({})", self.name(), joker.name(), joker.description());
+        player.add_joker(joker);
+        pause_after_print(400);
+    }
+}
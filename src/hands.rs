@@ -0,0 +1,152 @@
+use colored::*;
+use std::collections::HashMap;
+
+use crate::PokerHand;
+
+pub struct HandLevel {
+    pub level: u32,
+    pub chips: u64,
+    pub mult: u64,
+    pub times_played: u32,
+}
+
+// Per-run table of every poker hand's level, base score and play count
+pub struct HandLevels {
+    levels: HashMap<PokerHand, HandLevel>,
+}
+
+impl PokerHand {
+    // Listed from best to worst, the same as the in-game run info
+    pub const ALL: [PokerHand; 12] = [
+        PokerHand::FlushFive,
+        PokerHand::FlushHouse,
+        PokerHand::FiveOfAKind,
+        PokerHand::StraightFlush,
+        PokerHand::FourOfAKind,
+        PokerHand::FullHouse,
+        PokerHand::Flush,
+        PokerHand::Straight,
+        PokerHand::ThreeOfAKind,
+        PokerHand::TwoPair,
+        PokerHand::Pair,
+        PokerHand::HighCard,
+    ];
+
+    // Chips and mult at level 1
+    fn base_score(&self) -> (u64, u64) {
+        match self {
+            PokerHand::FlushFive => (160, 16),
+            PokerHand::FlushHouse => (140, 14),
+            PokerHand::FiveOfAKind => (120, 12),
+            PokerHand::StraightFlush => (100, 8),
+            PokerHand::FourOfAKind => (60, 7),
+            PokerHand::FullHouse => (40, 4),
+            PokerHand::Flush => (35, 4),
+            PokerHand::Straight => (30, 4),
+            PokerHand::ThreeOfAKind => (30, 3),
+            PokerHand::TwoPair => (20, 2),
+            PokerHand::Pair => (10, 2),
+            PokerHand::HighCard => (5, 1),
+        }
+    }
+
+    // Chips and mult gained for each level
+    pub fn level_increment(&self) -> (u64, u64) {
+        match self {
+            PokerHand::FlushFive => (50, 3),
+            PokerHand::FlushHouse => (40, 4),
+            PokerHand::FiveOfAKind => (35, 3),
+            PokerHand::StraightFlush => (40, 4),
+            PokerHand::FourOfAKind => (30, 3),
+            PokerHand::FullHouse => (25, 2),
+            PokerHand::Flush => (15, 2),
+            PokerHand::Straight => (30, 3),
+            PokerHand::ThreeOfAKind => (20, 2),
+            PokerHand::TwoPair => (20, 1),
+            PokerHand::Pair => (15, 1),
+            PokerHand::HighCard => (10, 1),
+        }
+    }
+}
+
+impl HandLevels {
+    pub fn new() -> Self {
+        let levels = PokerHand::ALL.iter()
+            .map(|&hand| {
+                let (chips, mult) = hand.base_score();
+                (hand, HandLevel { level: 1, chips, mult, times_played: 0 })
+            })
+            .collect();
+        Self { levels }
+    }
+
+    pub fn get(&self, hand: &PokerHand) -> &HandLevel {
+        &self.levels[hand]
+    }
+
+    // Returns (Chips, Mult) for the hand at its current level
+    pub fn score(&self, hand: &PokerHand) -> (u64, u64) {
+        let hand_level = self.get(hand);
+        (hand_level.chips, hand_level.mult)
+    }
+
+    pub fn level_up(&mut self, hand: &PokerHand, levels: u32) {
+        let (chips, mult) = hand.level_increment();
+        let hand_level = self.levels.get_mut(hand).unwrap();
+        hand_level.level += levels;
+        hand_level.chips += chips * levels as u64;
+        hand_level.mult += mult * levels as u64;
+    }
+
+    // Undo one level (never below level 1). Returns whether the level went down
+    pub fn level_down(&mut self, hand: &PokerHand) -> bool {
+        let (chips, mult) = hand.level_increment();
+        let hand_level = self.levels.get_mut(hand).unwrap();
+        if hand_level.level <= 1 {
+            return false;
+        }
+        hand_level.level -= 1;
+        hand_level.chips -= chips;
+        hand_level.mult -= mult;
+        true
+    }
+
+    pub fn record_play(&mut self, hand: &PokerHand) {
+        self.levels.get_mut(hand).unwrap().times_played += 1;
+    }
+
+    // How many hands have been played this run
+    pub fn total_played(&self) -> u32 {
+        self.levels.values().map(|hand_level| hand_level.times_played).sum()
+    }
+
+    // The poker hand played the most this run, if any have been played yet
+    pub fn most_played(&self) -> Option<PokerHand> {
+        PokerHand::ALL
+            .iter()
+            .filter(|hand| self.get(hand).times_played > 0)
+            .max_by_key(|hand| self.get(hand).times_played)
+            .copied()
+    }
+
+    pub fn print(&self) {
+        println!("\nPoker hands:");
+        for hand in PokerHand::ALL.iter() {
+            let hand_level = self.get(hand);
+            println!(
+                "{:<16} lvl.{:<3} {:>4} x {:<4} played {}",
+                hand.to_string(),
+                hand_level.level,
+                hand_level.chips.to_string().cyan(),
+                hand_level.mult.to_string().red(),
+                hand_level.times_played,
+            );
+        }
+    }
+}
+
+impl Default for HandLevels {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -1,29 +1,299 @@
 use colored::*;
+use rand::seq::SliceRandom;
+use rand::Rng;
 
 use crate::Card;
-use crate::Suit;
+use crate::PokerHand;
+use crate::cards::Edition;
+use crate::consumables::ConsumableKind;
+use crate::hands::HandLevels;
+use crate::scoring::{Effect, ScoreEvent};
 use crate::jokers::*;
 
-pub trait JokerAbility {
+#[derive(Clone, Copy, PartialEq)]
+pub enum Rarity {
+    Common,
+    Uncommon,
+    Rare,
+    Legendary,
+}
+
+impl Rarity {
+    // Base price of a joker of this rarity
+    pub fn cost(&self) -> i32 {
+        match self {
+            Rarity::Common => 4,
+            Rarity::Uncommon => 6,
+            Rarity::Rare => 8,
+            Rarity::Legendary => 20,
+        }
+    }
+
+    // Roll the rarity of a randomly generated joker; Legendary jokers only come from The Soul
+    pub fn random() -> Rarity {
+        let mut rng = rand::thread_rng();
+        match rng.gen_range(0..100) {
+            0..=69 => Rarity::Common,
+            70..=94 => Rarity::Uncommon,
+            _ => Rarity::Rare,
+        }
+    }
+}
+
+impl std::fmt::Display for Rarity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Rarity::Common => write!(f, "{}", "Common".blue()),
+            Rarity::Uncommon => write!(f, "{}", "Uncommon".green()),
+            Rarity::Rare => write!(f, "{}", "Rare".red()),
+            Rarity::Legendary => write!(f, "{}", "Legendary".purple()),
+        }
+    }
+}
+
+pub trait JokerAbility: CloneJokerAbility {
     fn name(&self) -> &str;
     fn description(&self) -> String;
 
+    fn rarity(&self) -> Rarity {
+        Rarity::Common
+    }
+
     // Joker ability that triggers when a hand is played
-    fn on_play(&self, chips: &mut u64, mult: &mut u64, cards: &[Card], played_cards: &Vec<usize>) {
-        // Default implementation is empty
+    fn on_play(&self, _cards: &[Card], _scoring_card_indeces: &[usize]) -> Option<Effect> {
+        None
     }
 
     // Joker ability that triggers when a card is scored
-    fn on_score(&self, card: &Card, chips: &mut u64, mult: &mut u64) {
-        // Default implementation is empty
+    fn on_score(&self, _card: &Card) -> Option<Effect> {
+        None
+    }
+
+    // Joker ability that triggers once every played card has scored
+    fn after_cards_scored(&self, _cards: &[Card], _scoring_card_indeces: &[usize]) -> Option<Effect> {
+        None
+    }
+
+    // Joker ability that triggers for each card held in hand when a hand is scored
+    fn on_held_in_hand(&self, _card: &Card) -> Option<Effect> {
+        None
     }
 
-    // Joker ability that triggers at the end of the round
-    fn end_of_round(&self, chips: &mut u64, mult: &mut u64, cards: &[Card], scoring_card_indeces: &Vec<usize>) {
-        // Default implementation is empty
+    // === Events ===
+    // Jokers that scale update their own counters here. Debuffed jokers don't hear about events
+
+    fn on_blind_selected(&mut self) {}
+
+    fn on_blind_skipped(&mut self) {}
+
+    // A hand was played, before it's scored. Its play has already been recorded in `hand_levels`
+    fn on_hand_played(&mut self, _cards: &[Card], _scoring_card_indeces: &[usize], _hand_type: &PokerHand, _hand_levels: &HandLevels) {}
+
+    // A hand finished scoring; anything that changes mid-scoring takes effect from the next hand
+    fn after_hand_scored(&mut self, _events: &[ScoreEvent]) {}
+
+    // Cards were discarded. `forced` discards come from boss blinds (The Hook) rather than the player, and jokers ignore them
+    fn on_discard(&mut self, _cards: &[Card], _forced: bool) -> Vec<JokerAction> {
+        Vec::new()
+    }
+
+    // The blind was beaten, at cash out. Money earned here shows as its own line in the rewards
+    fn on_round_won(&mut self, _cash_out: &CashOut) -> Vec<JokerAction> {
+        Vec::new()
+    }
+
+    // Extra money earned per $5 of interest at cash out (e.g. To the Moon)
+    fn extra_interest(&self) -> i32 {
+        0
+    }
+
+    fn on_shop_entered(&mut self) {}
+
+    fn on_shop_exited(&mut self) {}
+
+    // A playing card was added to the deck
+    fn on_card_added(&mut self, _card: &Card) {}
+
+    fn on_consumable_used(&mut self, _kind: ConsumableKind) {}
+
+    // Another joker was sold
+    fn on_joker_sold(&mut self, _joker: &Joker) {}
+
+    // Whether the joker has run out and should be destroyed (e.g. Ice Cream)
+    fn is_used_up(&self) -> bool {
+        false
+    }
+
+    // Sell value the joker has built up on top of its usual price (e.g. Egg)
+    fn extra_sell_value(&self) -> i32 {
+        0
     }
 }
 
+// Lets a boxed ability be copied along with its counters (e.g. by Ankh). Every joker gets this by deriving Clone
+pub trait CloneJokerAbility {
+    fn clone_box(&self) -> Box<dyn JokerAbility>;
+}
+
+impl<T: JokerAbility + Clone + 'static> CloneJokerAbility for T {
+    fn clone_box(&self) -> Box<dyn JokerAbility> {
+        Box::new(self.clone())
+    }
+}
+
+impl Clone for Box<dyn JokerAbility> {
+    fn clone(&self) -> Self {
+        self.clone_box()
+    }
+}
+
+// Something a joker does to the run outside of scoring a hand
+pub enum JokerAction {
+    Money(i32),
+    LevelUp(PokerHand),
+    DestroyCard(Card),  // removed from the deck for good
+    AddSellValue(i32),  // to every joker the player owns
+}
+
+// What jokers can see about the run when cashing out a won blind
+pub struct CashOut<'a> {
+    pub boss_defeated: bool,
+    pub deck: &'a [Card],  // the full deck, not just what's left to draw
+    pub discards_left: u8,
+    pub max_discards: u8,
+    pub planets_used: usize,  // unique Planet cards used this run
+}
+
+// A joker owned by the player: its ability plus any per-copy modifiers such as an edition
+#[derive(Clone)]
+pub struct Joker {
+    pub ability: Box<dyn JokerAbility>,
+    pub edition: Option<Edition>,
+    pub face_down: bool,  // hidden from view, but still scores (Amber Acorn)
+    pub disabled: bool,  // doesn't score at all (Crimson Heart)
+    pub eternal: bool,  // can't be sold or destroyed
+    pub perishable_rounds: Option<u8>,  // rounds left before it's debuffed for good
+    pub rental: bool,  // costs $3 at the end of every round
+    pub extra_value: i32,  // sell value added on top of its price (Gift Card)
+}
+
+impl Joker {
+    pub fn new(name: &str) -> Self {
+        Self {
+            ability: JokerFactory::create_joker(name),
+            edition: None,
+            face_down: false,
+            disabled: false,
+            eternal: false,
+            perishable_rounds: None,
+            rental: false,
+            extra_value: 0,
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        self.ability.name()
+    }
+
+    pub fn description(&self) -> String {
+        let mut description = match self.edition {
+            Some(edition) => format!("{} [{}: {}]", self.ability.description(), edition, edition.description()),
+            None => self.ability.description(),
+        };
+        if self.eternal {
+            description.push_str(&format!(" [{}]", "Eternal".magenta()));
+        }
+        match self.perishable_rounds {
+            Some(0) => description.push_str(&format!(" [{}]", "Perished".bright_black())),
+            Some(rounds) => description.push_str(&format!(" [{}: {} rounds left]", "Perishable".cyan(), rounds)),
+            None => {},
+        }
+        if self.rental {
+            description.push_str(&format!(" [{}: {} per round]", "Rental".yellow(), "-$3".yellow()));
+        }
+        description
+    }
+
+    // Whether the joker's ability counts this hand
+    pub fn is_active(&self) -> bool {
+        !self.disabled && self.perishable_rounds != Some(0)
+    }
+
+    // Negative jokers don't take up a joker slot
+    pub fn takes_slot(&self) -> bool {
+        self.edition != Some(Edition::Negative)
+    }
+
+    // Price based on rarity, plus extra for an edition
+    pub fn cost(&self) -> i32 {
+        let edition_cost = match self.edition {
+            Some(Edition::Foil) => 2,
+            Some(Edition::Holographic) => 3,
+            Some(Edition::Polychrome) | Some(Edition::Negative) => 5,
+            None => 0,
+        };
+        if self.rental {
+            return 1;
+        }
+        self.ability.rarity().cost() + edition_cost
+    }
+
+    pub fn sell_value(&self) -> i32 {
+        std::cmp::max(self.cost() / 2, 1) + self.ability.extra_sell_value() + self.extra_value
+    }
+}
+
+// Every joker that can show up during a run
+pub const JOKERS: [&str; 47] = [
+    "Joker",
+    "Greedy Joker",
+    "Lusty Joker",
+    "Wrathful Joker",
+    "Gluttonous Joker",
+    "Jolly Joker",
+    "Zany Joker",
+    "Mad Joker",
+    "Crazy Joker",
+    "Droll Joker",
+    "Sly Joker",
+    "Wily Joker",
+    "Clever Joker",
+    "Devious Joker",
+    "Crafty Joker",
+    "The Duo",
+    "The Trio",
+    "The Family",
+    "The Order",
+    "The Tribe",
+    "Triboulet",
+    "Green Joker",
+    "Ride the Bus",
+    "Runner",
+    "Ice Cream",
+    "Square Joker",
+    "Constellation",
+    "Hologram",
+    "Lucky Cat",
+    "Castle",
+    "Throwback",
+    "Wee Joker",
+    "Obelisk",
+    "Campfire",
+    "Hit the Road",
+    "Faceless Joker",
+    "Mail-In Rebate",
+    "Golden Joker",
+    "Egg",
+    "Trading Card",
+    "Rocket",
+    "Burnt Joker",
+    "Cloud 9",
+    "Delayed Gratification",
+    "To the Moon",
+    "Satellite",
+    "Gift Card",
+];
+
 pub struct JokerFactory {}
 
 impl JokerFactory {
@@ -44,7 +314,57 @@ impl JokerFactory {
             "Clever Joker" => Box::new(CleverJoker {}),
             "Devious Joker" => Box::new(DeviousJoker {}),
             "Crafty Joker" => Box::new(CraftyJoker {}),
+            "The Duo" => Box::new(TheDuo {}),
+            "The Trio" => Box::new(TheTrio {}),
+            "The Family" => Box::new(TheFamily {}),
+            "The Order" => Box::new(TheOrder {}),
+            "The Tribe" => Box::new(TheTribe {}),
+            "Triboulet" => Box::new(Triboulet {}),
+            "Green Joker" => Box::new(GreenJoker { mult: 0 }),
+            "Ride the Bus" => Box::new(RideTheBus { mult: 0 }),
+            "Runner" => Box::new(Runner { chips: 0 }),
+            "Ice Cream" => Box::new(IceCream { chips: 100 }),
+            "Square Joker" => Box::new(SquareJoker { chips: 0 }),
+            "Constellation" => Box::new(Constellation { xmult: 1.0 }),
+            "Hologram" => Box::new(Hologram { xmult: 1.0 }),
+            "Lucky Cat" => Box::new(LuckyCat { xmult: 1.0 }),
+            "Castle" => Box::new(Castle::new()),
+            "Throwback" => Box::new(Throwback { xmult: 1.0 }),
+            "Wee Joker" => Box::new(WeeJoker { chips: 0 }),
+            "Obelisk" => Box::new(Obelisk { xmult: 1.0 }),
+            "Campfire" => Box::new(Campfire { xmult: 1.0 }),
+            "Hit the Road" => Box::new(HitTheRoad { xmult: 1.0 }),
+            "Faceless Joker" => Box::new(FacelessJoker {}),
+            "Mail-In Rebate" => Box::new(MailInRebate::new()),
+            "Golden Joker" => Box::new(GoldenJoker {}),
+            "Egg" => Box::new(Egg { sell_value: 0 }),
+            "Trading Card" => Box::new(TradingCard { first_discard: true }),
+            "Rocket" => Box::new(Rocket { payout: 1 }),
+            "Burnt Joker" => Box::new(BurntJoker { first_discard: true }),
+            "Cloud 9" => Box::new(Cloud9 {}),
+            "Delayed Gratification" => Box::new(DelayedGratification {}),
+            "To the Moon" => Box::new(ToTheMoon {}),
+            "Satellite" => Box::new(Satellite {}),
+            "Gift Card" => Box::new(GiftCard {}),
             _ => Box::new(JimboJoker {}),  // default to Jimbo
         }
     }
+
+    // Pick a random joker of the given rarity, preferring ones the player doesn't already own.
+    // Falls back to Common jokers if there are none of that rarity.
+    pub fn random_joker(rarity: Rarity, owned: &[Joker]) -> &'static str {
+        let mut rng = rand::thread_rng();
+        let pool: Vec<&'static str> = JOKERS.iter()
+            .copied()
+            .filter(|name| JokerFactory::create_joker(name).rarity() == rarity)
+            .collect();
+        if pool.is_empty() && rarity != Rarity::Common {
+            return JokerFactory::random_joker(Rarity::Common, owned);
+        }
+        let unowned: Vec<&'static str> = pool.iter()
+            .copied()
+            .filter(|name| !owned.iter().any(|joker| joker.name() == *name))
+            .collect();
+        unowned.choose(&mut rng).or(pool.choose(&mut rng)).unwrap()
+    }
 }
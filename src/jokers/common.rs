@@ -1,12 +1,16 @@
 use colored::*;
+use rand::seq::SliceRandom;
 
 use crate::Card;
 use crate::Suit;
 use crate::PokerHand;
-use crate::jokers::base::JokerAbility;
+use crate::cards::Rank;
+use crate::jokers::base::{CashOut, JokerAbility, JokerAction};
 use crate::determine_poker_hand;
-use crate::pause_after_print;
+use crate::hands::HandLevels;
+use crate::scoring::{Effect, ScoreEvent};
 
+#[derive(Clone)]
 pub struct JimboJoker {}
 
 impl JokerAbility for JimboJoker {
@@ -22,13 +26,12 @@ impl JokerAbility for JimboJoker {
     }
 
     // +4 mult at end of the round
-    fn end_of_round(&self, chips: &mut u64, mult: &mut u64, cards: &[Card], scoring_card_indeces: &Vec<usize>) {
-        println!("{}: {} mult", self.name(), "+4".red());
-        *mult += 4;
-        pause_after_print(400);
+    fn after_cards_scored(&self, _cards: &[Card], _scoring_card_indeces: &[usize]) -> Option<Effect> {
+        Some(Effect::Mult(4.0))
     }
 }
 
+#[derive(Clone)]
 pub struct GreedyJoker {}
 
 impl JokerAbility for GreedyJoker {
@@ -46,15 +49,16 @@ impl JokerAbility for GreedyJoker {
     }
 
     // +3 mult for diamonds
-    fn on_score(&self, card: &Card, chips: &mut u64, mult: &mut u64) {
-        if card.suit == Suit::Diamonds {
-            println!("{}: {} mult", self.name(), "+3".red());
-            *mult += 3;
-            pause_after_print(400);
+    fn on_score(&self, card: &Card) -> Option<Effect> {
+        if card.is_suit(&Suit::Diamonds) {
+            Some(Effect::Mult(3.0))
+        } else {
+            None
         }
     }
 }
 
+#[derive(Clone)]
 pub struct LustyJoker {}
 
 impl JokerAbility for LustyJoker {
@@ -72,15 +76,16 @@ impl JokerAbility for LustyJoker {
     }
 
     // +3 mult for hearts
-    fn on_score(&self, card: &Card, chips: &mut u64, mult: &mut u64) {
-        if card.suit == Suit::Hearts {
-            println!("{}: {} mult", self.name(), "+3".red());
-            *mult += 3;
-            pause_after_print(400);
+    fn on_score(&self, card: &Card) -> Option<Effect> {
+        if card.is_suit(&Suit::Hearts) {
+            Some(Effect::Mult(3.0))
+        } else {
+            None
         }
     }
 }
 
+#[derive(Clone)]
 pub struct WrathfulJoker {}
 
 impl JokerAbility for WrathfulJoker {
@@ -98,15 +103,16 @@ impl JokerAbility for WrathfulJoker {
     }
 
     // +3 mult for spades
-    fn on_score(&self, card: &Card, chips: &mut u64, mult: &mut u64) {
-        if card.suit == Suit::Spades {
-            println!("{}: {} mult", self.name(), "+3".red());
-            *mult += 3;
-            pause_after_print(400);
+    fn on_score(&self, card: &Card) -> Option<Effect> {
+        if card.is_suit(&Suit::Spades) {
+            Some(Effect::Mult(3.0))
+        } else {
+            None
         }
     }
 }
 
+#[derive(Clone)]
 pub struct GluttonousJoker {}
 
 impl JokerAbility for GluttonousJoker {
@@ -124,15 +130,16 @@ impl JokerAbility for GluttonousJoker {
     }
 
     // +3 mult for clubs
-    fn on_score(&self, card: &Card, chips: &mut u64, mult: &mut u64) {
-        if card.suit == Suit::Clubs {
-            println!("{}: {} mult", self.name(), "+3".red());
-            *mult += 3;
-            pause_after_print(400);
+    fn on_score(&self, card: &Card) -> Option<Effect> {
+        if card.is_suit(&Suit::Clubs) {
+            Some(Effect::Mult(3.0))
+        } else {
+            None
         }
     }
 }
 
+#[derive(Clone)]
 pub struct JollyJoker {}
 
 impl JokerAbility for JollyJoker {
@@ -150,23 +157,20 @@ impl JokerAbility for JollyJoker {
     }
 
     // +8 mult if hand has PAIR
-    fn end_of_round(&self, chips: &mut u64, mult: &mut u64, cards: &[Card], scoring_card_indeces: &Vec<usize>) {
+    fn after_cards_scored(&self, cards: &[Card], scoring_card_indeces: &[usize]) -> Option<Effect> {
         // Get actual played cards that scored
         let scoring_cards: Vec<Card> = scoring_card_indeces.iter()
             .map(|&i| cards[i].clone())
             .collect();
         let (hand_type, _) = determine_poker_hand(&scoring_cards);
         match hand_type {
-            PokerHand::Pair | PokerHand::TwoPair | PokerHand::ThreeOfAKind | PokerHand::FullHouse | PokerHand::FourOfAKind | PokerHand::FiveOfAKind | PokerHand::FlushHouse | PokerHand::FlushFive => {
-                println!("{}: {} mult", self.name(), "+8".red());
-                *mult += 8;
-                pause_after_print(400);
-            },
-            _ => {},
-        };
+            PokerHand::Pair | PokerHand::TwoPair | PokerHand::ThreeOfAKind | PokerHand::FullHouse | PokerHand::FourOfAKind | PokerHand::FiveOfAKind | PokerHand::FlushHouse | PokerHand::FlushFive => Some(Effect::Mult(8.0)),
+            _ => None,
+        }
     }
 }
 
+#[derive(Clone)]
 pub struct ZanyJoker {}
 
 impl JokerAbility for ZanyJoker {
@@ -184,23 +188,20 @@ impl JokerAbility for ZanyJoker {
     }
 
     // +12 mult if hand has THREE OF A KIND
-    fn end_of_round(&self, chips: &mut u64, mult: &mut u64, cards: &[Card], scoring_card_indeces: &Vec<usize>) {
+    fn after_cards_scored(&self, cards: &[Card], scoring_card_indeces: &[usize]) -> Option<Effect> {
         // Get actual played cards that scored
         let scoring_cards: Vec<Card> = scoring_card_indeces.iter()
             .map(|&i| cards[i].clone())
             .collect();
         let (hand_type, _) = determine_poker_hand(&scoring_cards);
         match hand_type {
-            PokerHand::ThreeOfAKind | PokerHand::FullHouse | PokerHand::FourOfAKind | PokerHand::FiveOfAKind | PokerHand::FlushHouse | PokerHand::FlushFive => {
-                println!("{}: {} mult", self.name(), "+12".red());
-                *mult += 12;
-                pause_after_print(400);
-            },
-            _ => {},
-        };
+            PokerHand::ThreeOfAKind | PokerHand::FullHouse | PokerHand::FourOfAKind | PokerHand::FiveOfAKind | PokerHand::FlushHouse | PokerHand::FlushFive => Some(Effect::Mult(12.0)),
+            _ => None,
+        }
     }
 }
 
+#[derive(Clone)]
 pub struct MadJoker {}
 
 impl JokerAbility for MadJoker {
@@ -218,23 +219,20 @@ impl JokerAbility for MadJoker {
     }
 
     // +10 mult if hand has TWO PAIR
-    fn end_of_round(&self, chips: &mut u64, mult: &mut u64, cards: &[Card], scoring_card_indeces: &Vec<usize>) {
+    fn after_cards_scored(&self, cards: &[Card], scoring_card_indeces: &[usize]) -> Option<Effect> {
         // Get actual played cards that scored
         let scoring_cards: Vec<Card> = scoring_card_indeces.iter()
             .map(|&i| cards[i].clone())
             .collect();
         let (hand_type, _) = determine_poker_hand(&scoring_cards);
         match hand_type {
-            PokerHand::TwoPair | PokerHand::FullHouse | PokerHand::FlushHouse => {
-                println!("{}: {} mult", self.name(), "+10".red());
-                *mult += 10;
-                pause_after_print(400);
-            },
-            _ => {},
-        };
+            PokerHand::TwoPair | PokerHand::FullHouse | PokerHand::FlushHouse => Some(Effect::Mult(10.0)),
+            _ => None,
+        }
     }
 }
 
+#[derive(Clone)]
 pub struct CrazyJoker {}
 
 impl JokerAbility for CrazyJoker {
@@ -252,23 +250,20 @@ impl JokerAbility for CrazyJoker {
     }
 
     // +12 mult if hand has STRAIGHT
-    fn end_of_round(&self, chips: &mut u64, mult: &mut u64, cards: &[Card], scoring_card_indeces: &Vec<usize>) {
+    fn after_cards_scored(&self, cards: &[Card], scoring_card_indeces: &[usize]) -> Option<Effect> {
         // Get actual played cards that scored
         let scoring_cards: Vec<Card> = scoring_card_indeces.iter()
             .map(|&i| cards[i].clone())
             .collect();
         let (hand_type, _) = determine_poker_hand(&scoring_cards);
         match hand_type {
-            PokerHand::Straight | PokerHand::StraightFlush => {
-                println!("{}: {} mult", self.name(), "+12".red());
-                *mult += 12;
-                pause_after_print(400);
-            },
-            _ => {},
-        };
+            PokerHand::Straight | PokerHand::StraightFlush => Some(Effect::Mult(12.0)),
+            _ => None,
+        }
     }
 }
 
+#[derive(Clone)]
 pub struct DrollJoker {}
 
 impl JokerAbility for DrollJoker {
@@ -286,23 +281,20 @@ impl JokerAbility for DrollJoker {
     }
 
     // +10 mult if hand has FLUSH
-    fn end_of_round(&self, chips: &mut u64, mult: &mut u64, cards: &[Card], scoring_card_indeces: &Vec<usize>) {
+    fn after_cards_scored(&self, cards: &[Card], scoring_card_indeces: &[usize]) -> Option<Effect> {
         // Get actual played cards that scored
         let scoring_cards: Vec<Card> = scoring_card_indeces.iter()
             .map(|&i| cards[i].clone())
             .collect();
         let (hand_type, _) = determine_poker_hand(&scoring_cards);
         match hand_type {
-            PokerHand::Flush | PokerHand::StraightFlush | PokerHand::FlushHouse | PokerHand::FlushFive => {
-                println!("{}: {} mult", self.name(), "+10".red());
-                *mult += 10;
-                pause_after_print(400);
-            },
-            _ => {},
-        };
+            PokerHand::Flush | PokerHand::StraightFlush | PokerHand::FlushHouse | PokerHand::FlushFive => Some(Effect::Mult(10.0)),
+            _ => None,
+        }
     }
 }
 
+#[derive(Clone)]
 pub struct SlyJoker {}
 
 impl JokerAbility for SlyJoker {
@@ -320,23 +312,20 @@ impl JokerAbility for SlyJoker {
     }
 
     // +50 chips if hand has PAIR
-    fn end_of_round(&self, chips: &mut u64, mult: &mut u64, cards: &[Card], scoring_card_indeces: &Vec<usize>) {
+    fn after_cards_scored(&self, cards: &[Card], scoring_card_indeces: &[usize]) -> Option<Effect> {
         // Get actual played cards that scored
         let scoring_cards: Vec<Card> = scoring_card_indeces.iter()
             .map(|&i| cards[i].clone())
             .collect();
         let (hand_type, _) = determine_poker_hand(&scoring_cards);
         match hand_type {
-            PokerHand::Pair | PokerHand::TwoPair | PokerHand::ThreeOfAKind | PokerHand::FullHouse | PokerHand::FourOfAKind | PokerHand::FiveOfAKind | PokerHand::FlushHouse | PokerHand::FlushFive => {
-                println!("{}: {} chips", self.name(), "+50".cyan());
-                *chips += 50;
-                pause_after_print(400);
-            },
-            _ => {},
-        };
+            PokerHand::Pair | PokerHand::TwoPair | PokerHand::ThreeOfAKind | PokerHand::FullHouse | PokerHand::FourOfAKind | PokerHand::FiveOfAKind | PokerHand::FlushHouse | PokerHand::FlushFive => Some(Effect::Chips(50.0)),
+            _ => None,
+        }
     }
 }
 
+#[derive(Clone)]
 pub struct WilyJoker {}
 
 impl JokerAbility for WilyJoker {
@@ -354,23 +343,20 @@ impl JokerAbility for WilyJoker {
     }
 
     // +100 chips if hand has THREE OF A KIND
-    fn end_of_round(&self, chips: &mut u64, mult: &mut u64, cards: &[Card], scoring_card_indeces: &Vec<usize>) {
+    fn after_cards_scored(&self, cards: &[Card], scoring_card_indeces: &[usize]) -> Option<Effect> {
         // Get actual played cards that scored
         let scoring_cards: Vec<Card> = scoring_card_indeces.iter()
             .map(|&i| cards[i].clone())
             .collect();
         let (hand_type, _) = determine_poker_hand(&scoring_cards);
         match hand_type {
-            PokerHand::ThreeOfAKind | PokerHand::FullHouse | PokerHand::FourOfAKind | PokerHand::FiveOfAKind | PokerHand::FlushHouse | PokerHand::FlushFive => {
-                println!("{}: {} chips", self.name(), "+100".cyan());
-                *chips += 100;
-                pause_after_print(400);
-            },
-            _ => {},
-        };
+            PokerHand::ThreeOfAKind | PokerHand::FullHouse | PokerHand::FourOfAKind | PokerHand::FiveOfAKind | PokerHand::FlushHouse | PokerHand::FlushFive => Some(Effect::Chips(100.0)),
+            _ => None,
+        }
     }
 }
 
+#[derive(Clone)]
 pub struct CleverJoker {}
 
 impl JokerAbility for CleverJoker {
@@ -388,23 +374,20 @@ impl JokerAbility for CleverJoker {
     }
 
     // +80 chips if hand has TWO PAIR
-    fn end_of_round(&self, chips: &mut u64, mult: &mut u64, cards: &[Card], scoring_card_indeces: &Vec<usize>) {
+    fn after_cards_scored(&self, cards: &[Card], scoring_card_indeces: &[usize]) -> Option<Effect> {
         // Get actual played cards that scored
         let scoring_cards: Vec<Card> = scoring_card_indeces.iter()
             .map(|&i| cards[i].clone())
             .collect();
         let (hand_type, _) = determine_poker_hand(&scoring_cards);
         match hand_type {
-            PokerHand::TwoPair | PokerHand::FullHouse | PokerHand::FlushHouse => {
-                println!("{}: {} chips", self.name(), "+80".cyan());
-                *chips += 80;
-                pause_after_print(400);
-            },
-            _ => {},
-        };
+            PokerHand::TwoPair | PokerHand::FullHouse | PokerHand::FlushHouse => Some(Effect::Chips(80.0)),
+            _ => None,
+        }
     }
 }
 
+#[derive(Clone)]
 pub struct DeviousJoker {}
 
 impl JokerAbility for DeviousJoker {
@@ -422,23 +405,20 @@ impl JokerAbility for DeviousJoker {
     }
 
     // +100 chips if hand has STRAIGHT
-    fn end_of_round(&self, chips: &mut u64, mult: &mut u64, cards: &[Card], scoring_card_indeces: &Vec<usize>) {
+    fn after_cards_scored(&self, cards: &[Card], scoring_card_indeces: &[usize]) -> Option<Effect> {
         // Get actual played cards that scored
         let scoring_cards: Vec<Card> = scoring_card_indeces.iter()
             .map(|&i| cards[i].clone())
             .collect();
         let (hand_type, _) = determine_poker_hand(&scoring_cards);
         match hand_type {
-            PokerHand::Straight | PokerHand::StraightFlush => {
-                println!("{}: {} chips", self.name(), "+100".cyan());
-                *chips += 100;
-                pause_after_print(400);
-            },
-            _ => {},
-        };
+            PokerHand::Straight | PokerHand::StraightFlush => Some(Effect::Chips(100.0)),
+            _ => None,
+        }
     }
 }
 
+#[derive(Clone)]
 pub struct CraftyJoker {}
 
 impl JokerAbility for CraftyJoker {
@@ -456,19 +436,338 @@ impl JokerAbility for CraftyJoker {
     }
 
     // +80 chips if hand has FLUSH
-    fn end_of_round(&self, chips: &mut u64, mult: &mut u64, cards: &[Card], scoring_card_indeces: &Vec<usize>) {
+    fn after_cards_scored(&self, cards: &[Card], scoring_card_indeces: &[usize]) -> Option<Effect> {
         // Get actual played cards that scored
         let scoring_cards: Vec<Card> = scoring_card_indeces.iter()
             .map(|&i| cards[i].clone())
             .collect();
         let (hand_type, _) = determine_poker_hand(&scoring_cards);
         match hand_type {
-            PokerHand::Flush | PokerHand::StraightFlush | PokerHand::FlushHouse | PokerHand::FlushFive => {
-                println!("{}: {} chips", self.name(), "+80".cyan());
-                *chips += 80;
-                pause_after_print(400);
-            },
-            _ => {},
-        };
+            PokerHand::Flush | PokerHand::StraightFlush | PokerHand::FlushHouse | PokerHand::FlushFive => Some(Effect::Chips(80.0)),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct GreenJoker {
+    pub mult: u64,
+}
+
+impl JokerAbility for GreenJoker {
+    fn name(&self) -> &str {
+        "Green Joker"
+    }
+
+    fn description(&self) -> String {
+        format!(
+            "{} {} per hand played, {} {} per discard (Currently {} Mult)",
+            "+1".red().bold(),
+            "Mult".bold(),
+            "-1".red().bold(),
+            "Mult".bold(),
+            format!("+{}", self.mult).red(),
+        )
+    }
+
+    fn after_cards_scored(&self, _cards: &[Card], _scoring_card_indeces: &[usize]) -> Option<Effect> {
+        (self.mult > 0).then_some(Effect::Mult(self.mult as f64))
+    }
+
+    fn on_hand_played(&mut self, _cards: &[Card], _scoring_card_indeces: &[usize], _hand_type: &PokerHand, _hand_levels: &HandLevels) {
+        self.mult += 1;
+    }
+
+    fn on_discard(&mut self, _cards: &[Card], forced: bool) -> Vec<JokerAction> {
+        if forced {
+            return Vec::new();
+        }
+        self.mult = self.mult.saturating_sub(1);
+        Vec::new()
+    }
+}
+
+#[derive(Clone)]
+pub struct RideTheBus {
+    pub mult: u64,
+}
+
+impl JokerAbility for RideTheBus {
+    fn name(&self) -> &str {
+        "Ride the Bus"
+    }
+
+    fn description(&self) -> String {
+        format!(
+            "{} {} per consecutive hand played without a scoring {} (Currently {} Mult)",
+            "+1".red().bold(),
+            "Mult".bold(),
+            "face card".bold(),
+            format!("+{}", self.mult).red(),
+        )
+    }
+
+    fn after_cards_scored(&self, _cards: &[Card], _scoring_card_indeces: &[usize]) -> Option<Effect> {
+        (self.mult > 0).then_some(Effect::Mult(self.mult as f64))
+    }
+
+    // Resets as soon as a face card scores
+    fn on_hand_played(&mut self, cards: &[Card], scoring_card_indeces: &[usize], _hand_type: &PokerHand, _hand_levels: &HandLevels) {
+        let face_card_scored = scoring_card_indeces.iter().any(|&i| !cards[i].is_stone() && cards[i].rank.is_face());
+        self.mult = if face_card_scored { 0 } else { self.mult + 1 };
+    }
+}
+
+#[derive(Clone)]
+pub struct Runner {
+    pub chips: u64,
+}
+
+impl JokerAbility for Runner {
+    fn name(&self) -> &str {
+        "Runner"
+    }
+
+    fn description(&self) -> String {
+        format!(
+            "Gains {} {} if played hand contains a {} (Currently {} Chips)",
+            "+15".cyan().bold(),
+            "Chips".bold(),
+            "Straight".bold(),
+            format!("+{}", self.chips).cyan(),
+        )
+    }
+
+    fn after_cards_scored(&self, _cards: &[Card], _scoring_card_indeces: &[usize]) -> Option<Effect> {
+        (self.chips > 0).then_some(Effect::Chips(self.chips as f64))
+    }
+
+    fn on_hand_played(&mut self, _cards: &[Card], _scoring_card_indeces: &[usize], hand_type: &PokerHand, _hand_levels: &HandLevels) {
+        if matches!(hand_type, PokerHand::Straight | PokerHand::StraightFlush) {
+            self.chips += 15;
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct IceCream {
+    pub chips: u64,
+}
+
+impl JokerAbility for IceCream {
+    fn name(&self) -> &str {
+        "Ice Cream"
+    }
+
+    fn description(&self) -> String {
+        format!(
+            "{} {}, {} {} for every hand played",
+            format!("+{}", self.chips).cyan().bold(),
+            "Chips".bold(),
+            "-5".cyan().bold(),
+            "Chips".bold(),
+        )
+    }
+
+    fn after_cards_scored(&self, _cards: &[Card], _scoring_card_indeces: &[usize]) -> Option<Effect> {
+        Some(Effect::Chips(self.chips as f64))
+    }
+
+    // Melts after scoring, so the first hand still gets the full 100
+    fn after_hand_scored(&mut self, _events: &[ScoreEvent]) {
+        self.chips = self.chips.saturating_sub(5);
+    }
+
+    fn is_used_up(&self) -> bool {
+        self.chips == 0
+    }
+}
+
+#[derive(Clone)]
+pub struct SquareJoker {
+    pub chips: u64,
+}
+
+impl JokerAbility for SquareJoker {
+    fn name(&self) -> &str {
+        "Square Joker"
+    }
+
+    fn description(&self) -> String {
+        format!(
+            "Gains {} {} if played hand has exactly {} cards (Currently {} Chips)",
+            "+4".cyan().bold(),
+            "Chips".bold(),
+            "4".bold(),
+            format!("+{}", self.chips).cyan(),
+        )
+    }
+
+    fn after_cards_scored(&self, _cards: &[Card], _scoring_card_indeces: &[usize]) -> Option<Effect> {
+        (self.chips > 0).then_some(Effect::Chips(self.chips as f64))
+    }
+
+    fn on_hand_played(&mut self, cards: &[Card], _scoring_card_indeces: &[usize], _hand_type: &PokerHand, _hand_levels: &HandLevels) {
+        if cards.len() == 4 {
+            self.chips += 4;
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct FacelessJoker {}
+
+impl JokerAbility for FacelessJoker {
+    fn name(&self) -> &str {
+        "Faceless Joker"
+    }
+
+    fn description(&self) -> String {
+        format!(
+            "Earn {} if {} or more {} are discarded at the same time",
+            "$5".yellow().bold(),
+            "3".bold(),
+            "face cards".bold(),
+        )
+    }
+
+    fn on_discard(&mut self, cards: &[Card], forced: bool) -> Vec<JokerAction> {
+        if forced {
+            return Vec::new();
+        }
+        let face_cards = cards.iter().filter(|card| !card.is_stone() && card.rank.is_face()).count();
+        if face_cards >= 3 {
+            vec![JokerAction::Money(5)]
+        } else {
+            Vec::new()
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct MailInRebate {
+    pub rank: Rank,  // changes every round
+}
+
+impl MailInRebate {
+    pub fn new() -> Self {
+        Self { rank: random_rank() }
+    }
+}
+
+impl Default for MailInRebate {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl JokerAbility for MailInRebate {
+    fn name(&self) -> &str {
+        "Mail-In Rebate"
+    }
+
+    fn description(&self) -> String {
+        format!(
+            "Earn {} for each discarded {}, rank changes every round",
+            "$5".yellow().bold(),
+            self.rank.to_string().bold(),
+        )
+    }
+
+    fn on_discard(&mut self, cards: &[Card], forced: bool) -> Vec<JokerAction> {
+        if forced {
+            return Vec::new();
+        }
+        let matching = cards.iter().filter(|card| !card.is_stone() && card.rank == self.rank).count();
+        if matching > 0 {
+            vec![JokerAction::Money(5 * matching as i32)]
+        } else {
+            Vec::new()
+        }
+    }
+
+    fn on_round_won(&mut self, _cash_out: &CashOut) -> Vec<JokerAction> {
+        self.rank = random_rank();
+        Vec::new()
+    }
+}
+
+#[derive(Clone)]
+pub struct GoldenJoker {}
+
+impl JokerAbility for GoldenJoker {
+    fn name(&self) -> &str {
+        "Golden Joker"
+    }
+
+    fn description(&self) -> String {
+        format!("Earn {} at end of round", "$4".yellow().bold())
+    }
+
+    fn on_round_won(&mut self, _cash_out: &CashOut) -> Vec<JokerAction> {
+        vec![JokerAction::Money(4)]
+    }
+}
+
+#[derive(Clone)]
+pub struct Egg {
+    pub sell_value: i32,  // built up so far
+}
+
+impl JokerAbility for Egg {
+    fn name(&self) -> &str {
+        "Egg"
+    }
+
+    fn description(&self) -> String {
+        format!("Gains {} of {} at end of round", "$3".yellow().bold(), "sell value".bold())
+    }
+
+    fn on_round_won(&mut self, _cash_out: &CashOut) -> Vec<JokerAction> {
+        self.sell_value += 3;
+        Vec::new()
+    }
+
+    fn extra_sell_value(&self) -> i32 {
+        self.sell_value
+    }
+}
+
+fn random_rank() -> Rank {
+    *Rank::ALL.choose(&mut rand::thread_rng()).unwrap()
+}
+
+#[derive(Clone)]
+pub struct DelayedGratification {}
+
+impl JokerAbility for DelayedGratification {
+    fn name(&self) -> &str {
+        "Delayed Gratification"
+    }
+
+    fn description(&self) -> String {
+        format!("Earn {} per {} if no discards are used by end of round", "$2".yellow().bold(), "discard".bold())
+    }
+
+    // Only discards the player spent count; forced ones (The Hook) don't
+    fn on_round_won(&mut self, cash_out: &CashOut) -> Vec<JokerAction> {
+        if cash_out.discards_left < cash_out.max_discards || cash_out.discards_left == 0 {
+            Vec::new()
+        } else {
+            vec![JokerAction::Money(2 * cash_out.discards_left as i32)]
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hook_discards_dont_pay_mail_in_rebate() {
+        let mut joker = MailInRebate { rank: Rank::King };
+        let cards: Vec<Card> = ["Kh", "Ks"].iter().map(|card| card.parse().unwrap()).collect();
+        assert!(joker.on_discard(&cards, true).is_empty());
+        assert!(matches!(joker.on_discard(&cards, false).as_slice(), [JokerAction::Money(10)]));
     }
 }
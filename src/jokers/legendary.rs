@@ -0,0 +1,38 @@
+use colored::*;
+
+use crate::Card;
+use crate::cards::Rank;
+use crate::jokers::base::{JokerAbility, Rarity};
+use crate::scoring::Effect;
+
+#[derive(Clone)]
+pub struct Triboulet {}
+
+impl JokerAbility for Triboulet {
+    fn name(&self) -> &str {
+        "Triboulet"
+    }
+
+    fn description(&self) -> String {
+        format!(
+            "Played {} and {} each give {} {} when scored",
+            "Kings".bold(),
+            "Queens".bold(),
+            "X2".red().bold(),
+            "Mult".bold(),
+        )
+    }
+
+    fn rarity(&self) -> Rarity {
+        Rarity::Legendary
+    }
+
+    // x2 mult for kings and queens
+    fn on_score(&self, card: &Card) -> Option<Effect> {
+        if !card.is_stone() && (card.rank == Rank::King || card.rank == Rank::Queen) {
+            Some(Effect::XMult(2.0))
+        } else {
+            None
+        }
+    }
+}
@@ -1,5 +1,11 @@
 mod base;
 mod common;
+mod legendary;
+mod rare;
+mod uncommon;
 
-pub use base::{JokerAbility, JokerFactory};
-pub use common::*;
\ No newline at end of file
+pub use base::{CashOut, Joker, JokerAction, JokerFactory, Rarity};
+pub use common::*;
+pub use legendary::*;
+pub use rare::*;
+pub use uncommon::*;
\ No newline at end of file
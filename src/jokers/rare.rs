@@ -0,0 +1,348 @@
+use colored::*;
+
+use crate::Card;
+use crate::PokerHand;
+use crate::cards::Rank;
+use crate::jokers::base::{CashOut, Joker, JokerAbility, JokerAction, Rarity};
+use crate::determine_poker_hand;
+use crate::hands::HandLevels;
+use crate::scoring::{format_number, Effect, ScoreEvent};
+
+#[derive(Clone)]
+pub struct TheDuo {}
+
+impl JokerAbility for TheDuo {
+    fn name(&self) -> &str {
+        "The Duo"
+    }
+
+    fn description(&self) -> String {
+        format!(
+            "{} {} if played hand contains a {}",
+            "X2".red().bold(),
+            "Mult".bold(),
+            "Pair".bold()
+        )
+    }
+
+    fn rarity(&self) -> Rarity {
+        Rarity::Rare
+    }
+
+    // x2 mult if hand has PAIR
+    fn after_cards_scored(&self, cards: &[Card], scoring_card_indeces: &[usize]) -> Option<Effect> {
+        contains_hand(cards, scoring_card_indeces, PokerHand::Pair).then_some(Effect::XMult(2.0))
+    }
+}
+
+#[derive(Clone)]
+pub struct TheTrio {}
+
+impl JokerAbility for TheTrio {
+    fn name(&self) -> &str {
+        "The Trio"
+    }
+
+    fn description(&self) -> String {
+        format!(
+            "{} {} if played hand contains a {}",
+            "X3".red().bold(),
+            "Mult".bold(),
+            "Three of a Kind".bold()
+        )
+    }
+
+    fn rarity(&self) -> Rarity {
+        Rarity::Rare
+    }
+
+    // x3 mult if hand has THREE OF A KIND
+    fn after_cards_scored(&self, cards: &[Card], scoring_card_indeces: &[usize]) -> Option<Effect> {
+        contains_hand(cards, scoring_card_indeces, PokerHand::ThreeOfAKind).then_some(Effect::XMult(3.0))
+    }
+}
+
+#[derive(Clone)]
+pub struct TheFamily {}
+
+impl JokerAbility for TheFamily {
+    fn name(&self) -> &str {
+        "The Family"
+    }
+
+    fn description(&self) -> String {
+        format!(
+            "{} {} if played hand contains a {}",
+            "X4".red().bold(),
+            "Mult".bold(),
+            "Four of a Kind".bold()
+        )
+    }
+
+    fn rarity(&self) -> Rarity {
+        Rarity::Rare
+    }
+
+    // x4 mult if hand has FOUR OF A KIND
+    fn after_cards_scored(&self, cards: &[Card], scoring_card_indeces: &[usize]) -> Option<Effect> {
+        contains_hand(cards, scoring_card_indeces, PokerHand::FourOfAKind).then_some(Effect::XMult(4.0))
+    }
+}
+
+#[derive(Clone)]
+pub struct TheOrder {}
+
+impl JokerAbility for TheOrder {
+    fn name(&self) -> &str {
+        "The Order"
+    }
+
+    fn description(&self) -> String {
+        format!(
+            "{} {} if played hand contains a {}",
+            "X3".red().bold(),
+            "Mult".bold(),
+            "Straight".bold()
+        )
+    }
+
+    fn rarity(&self) -> Rarity {
+        Rarity::Rare
+    }
+
+    // x3 mult if hand has STRAIGHT
+    fn after_cards_scored(&self, cards: &[Card], scoring_card_indeces: &[usize]) -> Option<Effect> {
+        contains_hand(cards, scoring_card_indeces, PokerHand::Straight).then_some(Effect::XMult(3.0))
+    }
+}
+
+#[derive(Clone)]
+pub struct TheTribe {}
+
+impl JokerAbility for TheTribe {
+    fn name(&self) -> &str {
+        "The Tribe"
+    }
+
+    fn description(&self) -> String {
+        format!(
+            "{} {} if played hand contains a {}",
+            "X2".red().bold(),
+            "Mult".bold(),
+            "Flush".bold()
+        )
+    }
+
+    fn rarity(&self) -> Rarity {
+        Rarity::Rare
+    }
+
+    // x2 mult if hand has FLUSH
+    fn after_cards_scored(&self, cards: &[Card], scoring_card_indeces: &[usize]) -> Option<Effect> {
+        contains_hand(cards, scoring_card_indeces, PokerHand::Flush).then_some(Effect::XMult(2.0))
+    }
+}
+
+// Whether the scoring cards make up `hand` or a bigger hand that includes it, e.g. a Full House contains a Pair
+fn contains_hand(cards: &[Card], scoring_card_indeces: &[usize], hand: PokerHand) -> bool {
+    let scoring_cards: Vec<Card> = scoring_card_indeces.iter()
+        .map(|&i| cards[i].clone())
+        .collect();
+    let (hand_type, _) = determine_poker_hand(&scoring_cards);
+    match hand {
+        PokerHand::Pair => matches!(hand_type, PokerHand::Pair | PokerHand::TwoPair | PokerHand::ThreeOfAKind | PokerHand::FullHouse | PokerHand::FourOfAKind | PokerHand::FiveOfAKind | PokerHand::FlushHouse | PokerHand::FlushFive),
+        PokerHand::ThreeOfAKind => matches!(hand_type, PokerHand::ThreeOfAKind | PokerHand::FullHouse | PokerHand::FourOfAKind | PokerHand::FiveOfAKind | PokerHand::FlushHouse | PokerHand::FlushFive),
+        PokerHand::FourOfAKind => matches!(hand_type, PokerHand::FourOfAKind | PokerHand::FiveOfAKind | PokerHand::FlushFive),
+        PokerHand::Straight => matches!(hand_type, PokerHand::Straight | PokerHand::StraightFlush),
+        PokerHand::Flush => matches!(hand_type, PokerHand::Flush | PokerHand::StraightFlush | PokerHand::FlushHouse | PokerHand::FlushFive),
+        _ => hand_type == hand,
+    }
+}
+
+#[derive(Clone)]
+pub struct WeeJoker {
+    pub chips: u64,
+}
+
+impl JokerAbility for WeeJoker {
+    fn name(&self) -> &str {
+        "Wee Joker"
+    }
+
+    fn description(&self) -> String {
+        format!(
+            "Gains {} {} when each played {} is scored (Currently {} Chips)",
+            "+8".cyan().bold(),
+            "Chips".bold(),
+            "2".bold(),
+            format!("+{}", self.chips).cyan(),
+        )
+    }
+
+    fn rarity(&self) -> Rarity {
+        Rarity::Rare
+    }
+
+    fn after_cards_scored(&self, _cards: &[Card], _scoring_card_indeces: &[usize]) -> Option<Effect> {
+        (self.chips > 0).then_some(Effect::Chips(self.chips as f64))
+    }
+
+    // Retriggered 2s count again, debuffed ones don't count at all
+    fn after_hand_scored(&mut self, events: &[ScoreEvent]) {
+        let twos_scored = events.iter()
+            .filter(|event| matches!(event, ScoreEvent::CardScored { card, .. } if !card.is_stone() && card.rank == Rank::Two))
+            .count();
+        self.chips += 8 * twos_scored as u64;
+    }
+}
+
+#[derive(Clone)]
+pub struct Obelisk {
+    pub xmult: f64,
+}
+
+impl JokerAbility for Obelisk {
+    fn name(&self) -> &str {
+        "Obelisk"
+    }
+
+    fn description(&self) -> String {
+        format!(
+            "Gains {} {} per consecutive hand played without playing your most played {} (Currently {} Mult)",
+            "X0.2".red().bold(),
+            "Mult".bold(),
+            "poker hand".bold(),
+            format!("X{}", format_number(self.xmult)).red(),
+        )
+    }
+
+    fn rarity(&self) -> Rarity {
+        Rarity::Rare
+    }
+
+    fn after_cards_scored(&self, _cards: &[Card], _scoring_card_indeces: &[usize]) -> Option<Effect> {
+        (self.xmult > 1.0).then_some(Effect::XMult(self.xmult))
+    }
+
+    // Resets when no other hand has been played as often as this one
+    fn on_hand_played(&mut self, _cards: &[Card], _scoring_card_indeces: &[usize], hand_type: &PokerHand, hand_levels: &HandLevels) {
+        let times_played = hand_levels.get(hand_type).times_played;
+        let most_played = PokerHand::ALL.iter()
+            .all(|other| other == hand_type || hand_levels.get(other).times_played < times_played);
+        self.xmult = if most_played { 1.0 } else { self.xmult + 0.2 };
+    }
+}
+
+#[derive(Clone)]
+pub struct Campfire {
+    pub xmult: f64,
+}
+
+impl JokerAbility for Campfire {
+    fn name(&self) -> &str {
+        "Campfire"
+    }
+
+    fn description(&self) -> String {
+        format!(
+            "Gains {} {} for each card sold, resets when a {} is defeated (Currently {} Mult)",
+            "X0.25".red().bold(),
+            "Mult".bold(),
+            "Boss Blind".bold(),
+            format!("X{}", format_number(self.xmult)).red(),
+        )
+    }
+
+    fn rarity(&self) -> Rarity {
+        Rarity::Rare
+    }
+
+    fn after_cards_scored(&self, _cards: &[Card], _scoring_card_indeces: &[usize]) -> Option<Effect> {
+        (self.xmult > 1.0).then_some(Effect::XMult(self.xmult))
+    }
+
+    fn on_joker_sold(&mut self, _joker: &Joker) {
+        self.xmult += 0.25;
+    }
+
+    fn on_round_won(&mut self, cash_out: &CashOut) -> Vec<JokerAction> {
+        if cash_out.boss_defeated {
+            self.xmult = 1.0;
+        }
+        Vec::new()
+    }
+}
+
+#[derive(Clone)]
+pub struct HitTheRoad {
+    pub xmult: f64,
+}
+
+impl JokerAbility for HitTheRoad {
+    fn name(&self) -> &str {
+        "Hit the Road"
+    }
+
+    fn description(&self) -> String {
+        format!(
+            "Gains {} {} for every {} discarded this round (Currently {} Mult)",
+            "X0.5".red().bold(),
+            "Mult".bold(),
+            "Jack".bold(),
+            format!("X{}", format_number(self.xmult)).red(),
+        )
+    }
+
+    fn rarity(&self) -> Rarity {
+        Rarity::Rare
+    }
+
+    fn after_cards_scored(&self, _cards: &[Card], _scoring_card_indeces: &[usize]) -> Option<Effect> {
+        (self.xmult > 1.0).then_some(Effect::XMult(self.xmult))
+    }
+
+    fn on_discard(&mut self, cards: &[Card], forced: bool) -> Vec<JokerAction> {
+        if forced {
+            return Vec::new();
+        }
+        let jacks = cards.iter().filter(|card| !card.is_stone() && card.rank == Rank::Jack).count();
+        self.xmult += 0.5 * jacks as f64;
+        Vec::new()
+    }
+
+    fn on_round_won(&mut self, _cash_out: &CashOut) -> Vec<JokerAction> {
+        self.xmult = 1.0;
+        Vec::new()
+    }
+}
+
+#[derive(Clone)]
+pub struct BurntJoker {
+    pub first_discard: bool,  // whether the next discard is the round's first
+}
+
+impl JokerAbility for BurntJoker {
+    fn name(&self) -> &str {
+        "Burnt Joker"
+    }
+
+    fn description(&self) -> String {
+        format!("Upgrade the level of the first discarded {} each round", "poker hand".bold())
+    }
+
+    fn rarity(&self) -> Rarity {
+        Rarity::Rare
+    }
+
+    fn on_blind_selected(&mut self) {
+        self.first_discard = true;
+    }
+
+    fn on_discard(&mut self, cards: &[Card], forced: bool) -> Vec<JokerAction> {
+        if forced || !std::mem::replace(&mut self.first_discard, false) || cards.is_empty() {
+            return Vec::new();
+        }
+        let (hand_type, _) = determine_poker_hand(cards);
+        vec![JokerAction::LevelUp(hand_type)]
+    }
+}
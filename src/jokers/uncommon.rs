@@ -0,0 +1,370 @@
+use colored::*;
+use rand::seq::SliceRandom;
+
+use crate::Card;
+use crate::Suit;
+use crate::cards::{Enhancement, Rank};
+use crate::consumables::ConsumableKind;
+use crate::jokers::base::{CashOut, JokerAbility, JokerAction, Rarity};
+use crate::scoring::{format_number, Effect, ScoreEvent};
+
+#[derive(Clone)]
+pub struct Constellation {
+    pub xmult: f64,
+}
+
+impl JokerAbility for Constellation {
+    fn name(&self) -> &str {
+        "Constellation"
+    }
+
+    fn description(&self) -> String {
+        format!(
+            "Gains {} {} every time a {} card is used (Currently {} Mult)",
+            "X0.1".red().bold(),
+            "Mult".bold(),
+            "Planet".bold(),
+            format!("X{}", format_number(self.xmult)).red(),
+        )
+    }
+
+    fn rarity(&self) -> Rarity {
+        Rarity::Uncommon
+    }
+
+    fn after_cards_scored(&self, _cards: &[Card], _scoring_card_indeces: &[usize]) -> Option<Effect> {
+        (self.xmult > 1.0).then_some(Effect::XMult(self.xmult))
+    }
+
+    fn on_consumable_used(&mut self, kind: ConsumableKind) {
+        if kind == ConsumableKind::Planet {
+            self.xmult += 0.1;
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct Hologram {
+    pub xmult: f64,
+}
+
+impl JokerAbility for Hologram {
+    fn name(&self) -> &str {
+        "Hologram"
+    }
+
+    fn description(&self) -> String {
+        format!(
+            "Gains {} {} every time a playing card is added to your deck (Currently {} Mult)",
+            "X0.25".red().bold(),
+            "Mult".bold(),
+            format!("X{}", format_number(self.xmult)).red(),
+        )
+    }
+
+    fn rarity(&self) -> Rarity {
+        Rarity::Uncommon
+    }
+
+    fn after_cards_scored(&self, _cards: &[Card], _scoring_card_indeces: &[usize]) -> Option<Effect> {
+        (self.xmult > 1.0).then_some(Effect::XMult(self.xmult))
+    }
+
+    fn on_card_added(&mut self, _card: &Card) {
+        self.xmult += 0.25;
+    }
+}
+
+#[derive(Clone)]
+pub struct LuckyCat {
+    pub xmult: f64,
+}
+
+impl JokerAbility for LuckyCat {
+    fn name(&self) -> &str {
+        "Lucky Cat"
+    }
+
+    fn description(&self) -> String {
+        format!(
+            "Gains {} {} every time a {} card successfully triggers (Currently {} Mult)",
+            "X0.25".red().bold(),
+            "Mult".bold(),
+            "Lucky".bold(),
+            format!("X{}", format_number(self.xmult)).red(),
+        )
+    }
+
+    fn rarity(&self) -> Rarity {
+        Rarity::Uncommon
+    }
+
+    fn after_cards_scored(&self, _cards: &[Card], _scoring_card_indeces: &[usize]) -> Option<Effect> {
+        (self.xmult > 1.0).then_some(Effect::XMult(self.xmult))
+    }
+
+    fn after_hand_scored(&mut self, events: &[ScoreEvent]) {
+        let lucky_triggers = events.iter()
+            .filter(|event| matches!(event, ScoreEvent::EnhancementTriggered { enhancement: Enhancement::Lucky, .. }))
+            .count();
+        self.xmult += 0.25 * lucky_triggers as f64;
+    }
+}
+
+#[derive(Clone)]
+pub struct Castle {
+    pub chips: u64,
+    pub suit: Suit,  // changes every round
+}
+
+impl Castle {
+    pub fn new() -> Self {
+        Self { chips: 0, suit: random_suit() }
+    }
+}
+
+impl JokerAbility for Castle {
+    fn name(&self) -> &str {
+        "Castle"
+    }
+
+    fn description(&self) -> String {
+        format!(
+            "Gains {} {} per discarded {} card, suit changes every round (Currently {} Chips)",
+            "+3".cyan().bold(),
+            "Chips".bold(),
+            self.suit,
+            format!("+{}", self.chips).cyan(),
+        )
+    }
+
+    fn rarity(&self) -> Rarity {
+        Rarity::Uncommon
+    }
+
+    fn after_cards_scored(&self, _cards: &[Card], _scoring_card_indeces: &[usize]) -> Option<Effect> {
+        (self.chips > 0).then_some(Effect::Chips(self.chips as f64))
+    }
+
+    fn on_discard(&mut self, cards: &[Card], forced: bool) -> Vec<JokerAction> {
+        if forced {
+            return Vec::new();
+        }
+        let matching = cards.iter().filter(|card| card.is_suit(&self.suit)).count();
+        self.chips += 3 * matching as u64;
+        Vec::new()
+    }
+
+    fn on_round_won(&mut self, _cash_out: &CashOut) -> Vec<JokerAction> {
+        self.suit = random_suit();
+        Vec::new()
+    }
+}
+
+impl Default for Castle {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Clone)]
+pub struct Throwback {
+    pub xmult: f64,
+}
+
+impl JokerAbility for Throwback {
+    fn name(&self) -> &str {
+        "Throwback"
+    }
+
+    fn description(&self) -> String {
+        format!(
+            "Gains {} {} for each Blind skipped (Currently {} Mult)",
+            "X0.25".red().bold(),
+            "Mult".bold(),
+            format!("X{}", format_number(self.xmult)).red(),
+        )
+    }
+
+    fn rarity(&self) -> Rarity {
+        Rarity::Uncommon
+    }
+
+    fn after_cards_scored(&self, _cards: &[Card], _scoring_card_indeces: &[usize]) -> Option<Effect> {
+        (self.xmult > 1.0).then_some(Effect::XMult(self.xmult))
+    }
+
+    fn on_blind_skipped(&mut self) {
+        self.xmult += 0.25;
+    }
+}
+
+fn random_suit() -> Suit {
+    Suit::ALL.choose(&mut rand::thread_rng()).unwrap().clone()
+}
+
+#[derive(Clone)]
+pub struct TradingCard {
+    pub first_discard: bool,  // whether the next discard is the round's first
+}
+
+impl JokerAbility for TradingCard {
+    fn name(&self) -> &str {
+        "Trading Card"
+    }
+
+    fn description(&self) -> String {
+        format!(
+            "If {} of round has only {} card, destroy it and earn {}",
+            "first discard".bold(),
+            "1".bold(),
+            "$3".yellow().bold(),
+        )
+    }
+
+    fn rarity(&self) -> Rarity {
+        Rarity::Uncommon
+    }
+
+    fn on_blind_selected(&mut self) {
+        self.first_discard = true;
+    }
+
+    fn on_discard(&mut self, cards: &[Card], forced: bool) -> Vec<JokerAction> {
+        if forced {
+            return Vec::new();
+        }
+        let first_discard = std::mem::replace(&mut self.first_discard, false);
+        if first_discard && cards.len() == 1 {
+            vec![JokerAction::DestroyCard(cards[0].clone()), JokerAction::Money(3)]
+        } else {
+            Vec::new()
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct Rocket {
+    pub payout: i32,
+}
+
+impl JokerAbility for Rocket {
+    fn name(&self) -> &str {
+        "Rocket"
+    }
+
+    fn description(&self) -> String {
+        format!(
+            "Earn {} at end of round. Payout increases by {} when {} is defeated",
+            format!("${}", self.payout).yellow().bold(),
+            "$2".yellow().bold(),
+            "Boss Blind".bold(),
+        )
+    }
+
+    fn rarity(&self) -> Rarity {
+        Rarity::Uncommon
+    }
+
+    fn on_round_won(&mut self, cash_out: &CashOut) -> Vec<JokerAction> {
+        let payout = self.payout;
+        if cash_out.boss_defeated {
+            self.payout += 2;
+        }
+        vec![JokerAction::Money(payout)]
+    }
+}
+
+#[derive(Clone)]
+pub struct Cloud9 {}
+
+impl JokerAbility for Cloud9 {
+    fn name(&self) -> &str {
+        "Cloud 9"
+    }
+
+    fn description(&self) -> String {
+        format!("Earn {} for each {} in your {} at end of round", "$1".yellow().bold(), "9".bold(), "full deck".bold())
+    }
+
+    fn rarity(&self) -> Rarity {
+        Rarity::Uncommon
+    }
+
+    fn on_round_won(&mut self, cash_out: &CashOut) -> Vec<JokerAction> {
+        let nines = cash_out.deck.iter().filter(|card| !card.is_stone() && card.rank == Rank::Nine).count();
+        if nines > 0 {
+            vec![JokerAction::Money(nines as i32)]
+        } else {
+            Vec::new()
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct ToTheMoon {}
+
+impl JokerAbility for ToTheMoon {
+    fn name(&self) -> &str {
+        "To the Moon"
+    }
+
+    fn description(&self) -> String {
+        format!("Earn an extra {} of {} for every {} you have at end of round", "$1".yellow().bold(), "interest".bold(), "$5".yellow())
+    }
+
+    fn rarity(&self) -> Rarity {
+        Rarity::Uncommon
+    }
+
+    fn extra_interest(&self) -> i32 {
+        1
+    }
+}
+
+#[derive(Clone)]
+pub struct Satellite {}
+
+impl JokerAbility for Satellite {
+    fn name(&self) -> &str {
+        "Satellite"
+    }
+
+    fn description(&self) -> String {
+        format!("Earn {} at end of round per unique {} card used this run", "$1".yellow().bold(), "Planet".bold())
+    }
+
+    fn rarity(&self) -> Rarity {
+        Rarity::Uncommon
+    }
+
+    fn on_round_won(&mut self, cash_out: &CashOut) -> Vec<JokerAction> {
+        if cash_out.planets_used > 0 {
+            vec![JokerAction::Money(cash_out.planets_used as i32)]
+        } else {
+            Vec::new()
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct GiftCard {}
+
+impl JokerAbility for GiftCard {
+    fn name(&self) -> &str {
+        "Gift Card"
+    }
+
+    // Consumables can't be sold, so only jokers gain value
+    fn description(&self) -> String {
+        format!("Add {} of {} to every {} at end of round", "$1".yellow().bold(), "sell value".bold(), "Joker".bold())
+    }
+
+    fn rarity(&self) -> Rarity {
+        Rarity::Uncommon
+    }
+
+    fn on_round_won(&mut self, _cash_out: &CashOut) -> Vec<JokerAction> {
+        vec![JokerAction::AddSellValue(1)]
+    }
+}
@@ -4,20 +4,38 @@ use std::io::{self, Write};
 use std::thread;
 use std::time::Duration;
 use colored::*;
-use rand::Rng;
 
 mod jokers;
+mod bignum;
 mod blinds;
 mod cards;
+mod consumables;
+mod boosters;
+mod hands;
+mod scoring;
+mod shop;
+mod stakes;
+mod tags;
+mod vouchers;
 
-use jokers::{JokerAbility, JokerFactory};
-use blinds::{Blind, BlindType, BossBlindAbility};
-use cards::{Card, Suit};
+use jokers::{CashOut, Joker, JokerAction, JokerFactory, Rarity};
+use bignum::BigNum;
+use boosters::{BoosterPack, PackChoice, PackKind, PackSize};
+use blinds::{random_boss, AnteHistory, Blind, BlindType, DealContext};
+use cards::{Card, Edition, Enhancement, Rank, Seal, Suit};
+use consumables::{ConsumableAbility, ConsumableFactory, ConsumableKind};
+use hands::HandLevels;
+use scoring::{format_number, ScoringContext};
+use shop::{ForSale, Shop, ShopItem};
+use stakes::{RunConfig, Stake};
+use tags::{Tag, TagTiming};
+use vouchers::Voucher;
 
 pub fn pause_after_print(milliseconds: u64) {
     thread::sleep(Duration::from_millis(milliseconds));
 }
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum PokerHand {
     HighCard,
     Pair,
@@ -62,8 +80,12 @@ pub struct Player {
     // Passive game stats
     money: i32,  // start the run with $4
     deck: Vec<Card>,  // start with standard 52
-    jokers: Vec<Box<dyn jokers::JokerAbility>>,
-    // consumables: Vec<Consumable>,  // TBD; things like planet cards, tarot cards, and spectral cards
+    jokers: Vec<Joker>,
+    hand_levels: HandLevels,  // levels and play counts of each poker hand this run
+    consumables: Vec<Box<dyn ConsumableAbility>>,  // planet cards, tarot cards, and spectral cards
+    last_consumable_used: Option<String>,  // last tarot or planet card used, for The Fool
+    planets_used: Vec<String>,  // each different planet card used this run, for Satellite
+    vouchers: Vec<Voucher>,  // vouchers redeemed this run
 
     // Change per round
     current_deck: Vec<Card>,
@@ -75,8 +97,9 @@ pub struct Player {
     max_cards_in_hand: u8,  // starts at 8
     max_discards: u8,  // starts at 3
     max_hands: u8,  // starts at 4
-    max_jokers: u8,  // starts at 5
-    // max_consumables: u8,  // starts at 2; implementation TBD
+    max_jokers: u8,  // starts at 5; Negative jokers don't count towards this
+    max_consumables: u8,  // starts at 2
+    interest_cap: i32,  // starts at $5
     
     // UI preferences
     sort_method: SortMethod,
@@ -84,8 +107,15 @@ pub struct Player {
 
 impl Player {
     fn deal_hand(&mut self) {
-        while self.cards_in_hand.len() < self.max_cards_in_hand as usize {
-            if let Some(card) = self.current_deck.pop() {
+        let count = (self.max_cards_in_hand as usize).saturating_sub(self.cards_in_hand.len());
+        self.draw_cards(count, |_| false);
+    }
+
+    // Draw up to `count` cards from the deck, asking `face_down` about each one
+    fn draw_cards<F: FnMut(&Card) -> bool>(&mut self, count: usize, mut face_down: F) {
+        for _ in 0..count {
+            if let Some(mut card) = self.current_deck.pop() {
+                card.face_down = face_down(&card);
                 self.cards_in_hand.push(card);
             } else {
                 break;
@@ -100,15 +130,49 @@ impl Player {
         self.current_deck.shuffle(&mut rng);
     }
 
+    // Reset for a new round; the caller deals the first hand
     fn start_round(&mut self) {
         self.cards_in_hand.clear();
         self.shuffle_deck();
-        self.deal_hand();
         self.hands = self.max_hands;
         self.discards = self.max_discards;
     }
     
-    fn discard_cards(&mut self, indices: &[usize], noisy: bool) {
+    // `forced` discards are made by boss blinds rather than the player
+    fn discard_cards(&mut self, indices: &[usize], noisy: bool, forced: bool) {
+        // Purple Seals create a tarot card when discarded
+        let mut purple_seals = 0;
+        for &idx in indices {
+            if let Some(card) = self.cards_in_hand.get(idx) {
+                if card.seal == Some(Seal::Purple) {
+                    purple_seals += 1;
+                }
+            }
+        }
+        for _ in 0..purple_seals {
+            let tarot = consumables::random_tarot();
+            if self.add_consumable(tarot) {
+                println!("{}: {} created", Seal::Purple, tarot);
+                pause_after_print(400);
+            }
+        }
+
+        let discarded: Vec<Card> = indices.iter().filter_map(|&idx| self.cards_in_hand.get(idx).cloned()).collect();
+        let mut actions = Vec::new();
+        for joker in self.active_jokers_mut() {
+            let name = joker.name().to_string();
+            actions.extend(joker.ability.on_discard(&discarded, forced).into_iter().map(|action| (name.clone(), action)));
+        }
+        for (source, action) in actions {
+            self.apply_joker_action(&source, action);
+        }
+
+        // The caller deals new cards to replace the discarded ones
+        self.remove_cards(indices, noisy);
+    }
+
+    // Remove cards from the hand without dealing replacements
+    fn remove_cards(&mut self, indices: &[usize], noisy: bool) {
         // Sort indices in descending order to avoid shifting issues when removing
         let mut sorted_indices = indices.to_vec();
         sorted_indices.sort_by(|a, b| b.cmp(a));
@@ -123,37 +187,30 @@ impl Player {
                 self.cards_in_hand.remove(idx);
             }
         }
-        
-        // Deal new cards to replace the discarded ones
-        self.deal_hand();
+    }
+
+    // Permanently remove a card from the player's deck (e.g. a shattered Glass card)
+    fn remove_card_from_deck(&mut self, card: &Card) {
+        if let Some(pos) = self.deck.iter().position(|c| c == card) {
+            self.deck.remove(pos);
+        }
     }
     
     // Sort cards in hand based on current sort method
     fn sort_cards_in_hand(&mut self) {
+        // Face-down cards stay at the end in the order they were dealt, so sorting doesn't give them away
+        let face_down: Vec<Card> = self.cards_in_hand.iter().filter(|card| card.face_down).cloned().collect();
+        self.cards_in_hand.retain(|card| !card.face_down);
         match self.sort_method {
             SortMethod::ByRank => self.sort_by_rank(),
             SortMethod::BySuit => self.sort_by_suit(),
         }
+        self.cards_in_hand.extend(face_down);
     }
 
     // Sort cards by rank (2,3,4,...,J,Q,K,A)
     fn sort_by_rank(&mut self) {
-        self.cards_in_hand.sort_by(|a, b| {
-            let rank_value = |rank: &str| -> u8 {
-                match rank {
-                    "A" => 14,
-                    "K" => 13,
-                    "Q" => 12,
-                    "J" => 11,
-                    _ => rank.parse::<u8>().unwrap_or(0),
-                }
-            };
-            
-            let a_value = rank_value(&a.rank);
-            let b_value = rank_value(&b.rank);
-            
-            a_value.cmp(&b_value)
-        });
+        self.cards_in_hand.sort_by_key(|card| card.rank);
     }
     
     // Sort cards by suit (♠,♥,♣,♦) and then by rank within each suit
@@ -168,22 +225,12 @@ impl Player {
                 }
             };
             
-            let rank_value = |rank: &str| -> u8 {
-                match rank {
-                    "A" => 14,
-                    "K" => 13,
-                    "Q" => 12,
-                    "J" => 11,
-                    _ => rank.parse::<u8>().unwrap_or(0),
-                }
-            };
-            
             let a_suit = suit_value(&a.suit);
             let b_suit = suit_value(&b.suit);
             
             if a_suit == b_suit {
                 // If suits are the same, sort by rank
-                rank_value(&a.rank).cmp(&rank_value(&b.rank))
+                a.rank.cmp(&b.rank)
             } else {
                 // Otherwise sort by suit
                 a_suit.cmp(&b_suit)
@@ -200,6 +247,209 @@ impl Player {
         self.sort_cards_in_hand();
     }
     
+    // Whether there's room for another joker that takes up a slot
+    fn has_joker_slot(&self) -> bool {
+        let used_slots = self.jokers.iter().filter(|joker| joker.takes_slot()).count();
+        used_slots < self.max_jokers as usize
+    }
+
+    // Add a consumable if there's a free slot. Returns whether it was added
+    fn add_consumable(&mut self, name: &str) -> bool {
+        if self.consumables.len() >= self.max_consumables as usize {
+            println!("No room for {}! Consumable slots are full ({}).", name, self.max_consumables);
+            pause_after_print(400);
+            return false;
+        }
+        self.consumables.push(ConsumableFactory::create_consumable(name));
+        true
+    }
+
+    // Use the consumable at `index` on the selected cards in hand. Returns whether it was used
+    fn use_consumable(&mut self, index: usize, selected: &[usize]) -> bool {
+        if index >= self.consumables.len() {
+            println!("Invalid consumable index: {}", index);
+            return false;
+        }
+        let Some(selected) = self.check_consumable_use(self.consumables[index].as_ref(), selected) else {
+            return false;
+        };
+
+        let consumable = self.consumables.remove(index);
+        self.apply_consumable(consumable, &selected);
+        true
+    }
+
+    // Check whether a consumable can be used on the selected cards in hand, returning the selection in order if so
+    fn check_consumable_use(&self, consumable: &dyn ConsumableAbility, selected: &[usize]) -> Option<Vec<usize>> {
+        // Selected cards are applied left to right (e.g. for Death)
+        let mut selected = selected.to_vec();
+        selected.sort();
+        selected.dedup();
+        let (min, max) = consumable.selection_range();
+        if selected.len() < min || selected.len() > max {
+            if min == max {
+                println!("{} needs exactly {} selected card(s)!", consumable.name(), min);
+            } else {
+                println!("{} needs between {} and {} selected cards!", consumable.name(), min, max);
+            }
+            return None;
+        }
+        if let Some(&idx) = selected.iter().find(|&&idx| idx >= self.cards_in_hand.len()) {
+            println!("Invalid card index: {}", idx);
+            return None;
+        }
+        if !consumable.can_use(self) {
+            println!("{} can't be used right now!", consumable.name());
+            return None;
+        }
+        Some(selected)
+    }
+
+    // Use a consumable that has already passed `check_consumable_use`
+    fn apply_consumable(&mut self, consumable: Box<dyn ConsumableAbility>, selected: &[usize]) {
+        consumable.use_consumable(self, selected);
+        for joker in self.active_jokers_mut() {
+            joker.ability.on_consumable_used(consumable.kind());
+        }
+        if consumable.kind() == ConsumableKind::Planet && !self.planets_used.iter().any(|name| name == consumable.name()) {
+            self.planets_used.push(consumable.name().to_string());
+        }
+        if consumable.kind() != ConsumableKind::Spectral && consumable.name() != "The Fool" {
+            self.last_consumable_used = Some(consumable.name().to_string());
+        }
+    }
+
+    // === Shared mutation API ===
+    // Consumables and other effects go through these so that the hand, deck, jokers and hand size stay consistent
+
+    // Apply a change to a card in hand, mirroring it onto the player's deck so it persists between rounds
+    fn modify_card_in_hand<F: FnOnce(&mut Card)>(&mut self, index: usize, modify: F) {
+        let deck_pos = self.deck.iter().position(|card| *card == self.cards_in_hand[index]);
+        modify(&mut self.cards_in_hand[index]);
+        if let Some(pos) = deck_pos {
+            self.deck[pos] = self.cards_in_hand[index].clone();
+        }
+    }
+
+    // Destroy cards in hand, removing them from the player's deck as well
+    fn destroy_cards_in_hand(&mut self, indices: &[usize]) {
+        let mut sorted_indices = indices.to_vec();
+        sorted_indices.sort_by(|a, b| b.cmp(a));
+        for &idx in &sorted_indices {
+            if idx < self.cards_in_hand.len() {
+                let card = self.cards_in_hand.remove(idx);
+                self.remove_card_from_deck(&card);
+            }
+        }
+    }
+
+    // Add a new card to the hand, and to the player's deck so it's kept for future rounds
+    fn add_card_to_hand(&mut self, card: Card) {
+        self.add_card_to_deck(card.clone());
+        self.cards_in_hand.push(card);
+    }
+
+    // Add a new card straight to the player's deck (e.g. from a Standard pack)
+    fn add_card_to_deck(&mut self, card: Card) {
+        for joker in self.active_jokers_mut() {
+            joker.ability.on_card_added(&card);
+        }
+        self.deck.push(card);
+    }
+
+    // Add a joker if there's room for it (Negative jokers always fit). Returns whether it was added
+    fn add_joker(&mut self, joker: Joker) -> bool {
+        if joker.takes_slot() && !self.has_joker_slot() {
+            println!("No room for {}! Joker slots are full ({}).", joker.name(), self.max_jokers);
+            pause_after_print(400);
+            return false;
+        }
+        self.jokers.push(joker);
+        true
+    }
+
+    // Sell the joker at `index`. Returns whether it was sold
+    fn sell_joker(&mut self, index: usize) -> bool {
+        if index >= self.jokers.len() {
+            println!("Invalid joker index!");
+            return false;
+        }
+        if self.jokers[index].eternal {
+            println!("{} is Eternal and can't be sold!", self.jokers[index].name());
+            return false;
+        }
+        let joker = self.jokers.remove(index);
+        self.money += joker.sell_value();
+        println!("Sold {} for {}", joker.name(), format!("${}", joker.sell_value()).yellow());
+        for other in self.active_jokers_mut() {
+            other.ability.on_joker_sold(&joker);
+        }
+        true
+    }
+
+    // Carry out something a joker did outside of scoring
+    fn apply_joker_action(&mut self, source: &str, action: JokerAction) {
+        match action {
+            JokerAction::Money(amount) => {
+                self.money += amount;
+                println!("{}: {}", source, format!("+${}", amount).yellow());
+            },
+            JokerAction::LevelUp(hand) => {
+                self.hand_levels.level_up(&hand, 1);
+                println!("{}: {} upgraded to level {}", source, hand, self.hand_levels.get(&hand).level);
+            },
+            JokerAction::DestroyCard(card) => {
+                self.remove_card_from_deck(&card);
+                println!("{}: {} destroyed", source, card);
+            },
+            JokerAction::AddSellValue(amount) => {
+                for joker in self.jokers.iter_mut() {
+                    joker.extra_value += amount;
+                }
+                println!("{}: all Jokers gained {} of sell value", source, format!("${}", amount).yellow());
+            },
+        }
+        pause_after_print(400);
+    }
+
+    // Jokers that aren't debuffed, for passing events on to
+    fn active_jokers_mut(&mut self) -> impl Iterator<Item = &mut Joker> {
+        self.jokers.iter_mut().filter(|joker| joker.is_active())
+    }
+
+    // Destroy jokers that have run out (e.g. Ice Cream)
+    fn remove_used_up_jokers(&mut self) {
+        self.jokers.retain(|joker| {
+            if joker.ability.is_used_up() {
+                println!("{} is used up!", joker.name());
+                pause_after_print(400);
+            }
+            !joker.ability.is_used_up()
+        });
+    }
+
+    // Destroy every joker except the one at `keep` and any Eternal ones
+    fn destroy_other_jokers(&mut self, keep: usize) {
+        let mut i = 0;
+        self.jokers.retain(|joker| {
+            let kept = i == keep || joker.eternal;
+            if !kept {
+                println!("{} destroyed", joker.name());
+                pause_after_print(200);
+            }
+            i += 1;
+            kept
+        });
+    }
+
+    // Permanently change the number of cards held in hand (never dropping below 1)
+    fn change_hand_size(&mut self, delta: i8) {
+        let new_size = (self.max_cards_in_hand as i8 + delta).max(1);
+        self.max_cards_in_hand = new_size as u8;
+        println!("Hand size is now {}", self.max_cards_in_hand);
+        pause_after_print(400);
+    }
+
     // Move a joker from one position to another, shifting other jokers as needed
     fn move_joker(&mut self, indices: &[usize]) -> bool {
         if indices.len() != 2 {
@@ -222,15 +472,28 @@ impl Player {
 
 pub struct Round {
     blind: Blind,
-    score: u64,
+    score: BigNum,
+    last_hand_played: Option<PokerHand>,
+    skip_tag: Option<Tag>,  // offered for skipping the blind; Boss blinds can't be skipped
+    hand_size_change: i8,  // hand size for this round only (e.g. Juggle Tag, boss blinds)
 }
 
 impl Round {
-    fn new(ante: u8, blind_type: BlindType) -> Self {
-        let blind = Blind::new(blind_type, ante);
+    fn new(ante: u8, blind_type: BlindType, boss: &str, config: &RunConfig) -> Self {
+        let blind = match blind_type {
+            BlindType::Boss => Blind::boss(boss, ante, config),
+            _ => Blind::new(blind_type, ante, config),
+        };
+        let skip_tag = match blind_type {
+            BlindType::Boss => None,
+            _ => Some(Tag::random(ante)),
+        };
         Self {
             blind,
-            score: 0,
+            score: BigNum::ZERO,
+            last_hand_played: None,
+            skip_tag,
+            hand_size_change: 0,
         }
     }
 }
@@ -240,27 +503,45 @@ pub struct GameManager {
     current_blind: BlindType,
     current_round: Round,
     player: Player,
+    shop: Shop,
+    tags: Vec<Tag>,  // tags waiting to take effect
+    blinds_skipped: u32,
+    unused_discards: u32,  // discards left over at the end of each won round
+    ante_history: AnteHistory,  // cleared when a new ante begins
+    upcoming_boss: &'static str,  // decided when the ante begins
+    boss_rerolls: u32,  // this ante
+    config: RunConfig,  // chosen when the run starts
 }
 
 impl GameManager {
-    fn new(mut player: Player) -> Self {
+    fn new(player: Player, config: RunConfig) -> Self {
         let ante = 1;
         let current_blind = BlindType::Small;
-        player.start_round();
-        let current_round = Round::new(ante, current_blind);
+        let upcoming_boss = random_boss(ante, None);
+        let current_round = Round::new(ante, current_blind, upcoming_boss, &config);
+        let mut shop = Shop::new(config);
+        shop.restock_voucher(&player.vouchers);
         
         Self {
             ante,
             current_blind,
             current_round,
-            player
+            player,
+            shop,
+            tags: Vec::new(),
+            blinds_skipped: 0,
+            unused_discards: 0,
+            ante_history: AnteHistory::default(),
+            upcoming_boss,
+            boss_rerolls: 0,
+            config,
         }
     }
 
     fn calculate_interest(&self) -> i32 {
         let base_money = self.player.money;
-        let interest_units = base_money / 5;  // Integer division to get complete units of $5
-        std::cmp::min(interest_units, 5)  // Cap at $5 maximum interest
+        let interest_units = std::cmp::max(base_money / 5, 0);  // Integer division to get complete units of $5; no interest while in debt
+        std::cmp::min(interest_units, self.player.interest_cap)  // Cap at $5 maximum interest, unless raised by vouchers
     }
 
     fn calculate_remaining_hands_bonus(&self) -> i32 {
@@ -269,17 +550,54 @@ impl GameManager {
 
     fn get_blind_bounty(&self) -> i32 {
         match self.current_blind {
+            BlindType::Small if !self.config.small_blind_reward() => 0,
             BlindType::Small => 3,
             BlindType::Big => 4,
             BlindType::Boss => 5,
         }
     }
 
+    fn calculate_gold_cards_bonus(&self) -> i32 {
+        // $3 for each Gold card still held in hand
+        let gold_cards = self.player.cards_in_hand.iter()
+            .filter(|card| card.enhancement == Some(Enhancement::Gold))
+            .count();
+        gold_cards as i32 * 3
+    }
+
     fn award_round_rewards(&mut self) {
         // Calculate all rewards
         let blind_bounty = self.get_blind_bounty();
         let hands_bonus = self.calculate_remaining_hands_bonus();
         let interest = self.calculate_interest();
+        let gold_bonus = self.calculate_gold_cards_bonus();
+
+        // Jokers that pay out at cash out get their own line; anything else they do happens right away
+        let cash_out = CashOut {
+            boss_defeated: self.current_blind == BlindType::Boss,
+            deck: &self.player.deck,
+            discards_left: self.player.discards,
+            max_discards: self.player.max_discards,
+            planets_used: self.player.planets_used.len(),
+        };
+        let mut joker_payouts = Vec::new();
+        let mut joker_actions = Vec::new();
+        for joker in self.player.jokers.iter_mut().filter(|joker| joker.is_active()) {
+            // Jokers that change the interest rules pay out on top of each $1 of interest
+            let extra_interest = interest * joker.ability.extra_interest();
+            if extra_interest > 0 {
+                joker_payouts.push((joker.name().to_string(), extra_interest));
+            }
+            for action in joker.ability.on_round_won(&cash_out) {
+                match action {
+                    JokerAction::Money(amount) => joker_payouts.push((joker.name().to_string(), amount)),
+                    action => joker_actions.push((joker.name().to_string(), action)),
+                }
+            }
+        }
+        for (source, action) in joker_actions {
+            self.player.apply_joker_action(&source, action);
+        }
         
         // Print reward breakdown
         println!("\nRewards:");
@@ -287,29 +605,301 @@ impl GameManager {
         pause_after_print(400);
         println!("Remaining hands bonus: {}", format!("${}", hands_bonus).yellow());
         pause_after_print(400);
+        if gold_bonus > 0 {
+            println!("Gold cards held: {}", format!("${}", gold_bonus).yellow());
+            pause_after_print(400);
+        }
+        for (joker, amount) in &joker_payouts {
+            println!("{}: {}", joker, format!("${}", amount).yellow());
+            pause_after_print(400);
+        }
         println!("Interest earned: {}", format!("${}", interest).yellow());
         pause_after_print(800);
         
         // Add all rewards to player's money
-        let total_reward = blind_bounty + hands_bonus + interest;
+        let joker_total: i32 = joker_payouts.iter().map(|(_, amount)| amount).sum();
+        let total_reward = blind_bounty + hands_bonus + interest + gold_bonus + joker_total;
         self.player.money += total_reward;
         println!("\nTotal reward: {}", format!("${}", total_reward).yellow());
         pause_after_print(400);
         println!("New balance: {}", format!("${}", self.player.money).yellow().bold());
         pause_after_print(2000);
+
+        self.unused_discards += self.player.discards as u32;
+        if self.current_blind == BlindType::Boss {
+            self.trigger_tags(TagTiming::BossDefeated);
+        }
+        self.trigger_joker_stickers();
+    }
+
+    // End of round costs for Rental jokers, and Perishable jokers wearing out
+    fn trigger_joker_stickers(&mut self) {
+        for joker in self.player.jokers.iter_mut() {
+            if joker.rental {
+                self.player.money -= 3;
+                println!("{} rent: {}", joker.name(), "-$3".yellow());
+                pause_after_print(400);
+            }
+            if let Some(rounds) = joker.perishable_rounds.filter(|&rounds| rounds > 0) {
+                joker.perishable_rounds = Some(rounds - 1);
+                if rounds == 1 {
+                    println!("{} has perished!", joker.name());
+                    pause_after_print(400);
+                }
+            }
+        }
+    }
+
+    // End of round effects for seals on cards still held in hand
+    fn trigger_held_card_seals(&mut self) {
+        // Blue Seals create the planet card for the final hand played this round
+        if let Some(hand_type) = self.current_round.last_hand_played {
+            let mut blue_seals = 0;
+            for card in &self.player.cards_in_hand {
+                if card.seal == Some(Seal::Blue) {
+                    blue_seals += 1;
+                }
+            }
+            for _ in 0..blue_seals {
+                let planet = consumables::planet_for_hand(&hand_type);
+                if self.player.add_consumable(planet) {
+                    println!("{}: {} created", Seal::Blue, planet);
+                    pause_after_print(400);
+                }
+            }
+        }
     }
 
     fn next_round(&mut self) {
+        // Undo any hand size that was only for the round just finished
+        self.player.max_cards_in_hand = (self.player.max_cards_in_hand as i8 - self.current_round.hand_size_change) as u8;
         self.current_blind = match self.current_blind {
             BlindType::Small => BlindType::Big,
             BlindType::Big => BlindType::Boss,
             BlindType::Boss => {
                 self.ante += 1;
+                self.ante_history = AnteHistory::default();
+                self.upcoming_boss = random_boss(self.ante, None);
+                self.boss_rerolls = 0;
+                self.shop.restock_voucher(&self.player.vouchers);
                 BlindType::Small
             }
         };
-        self.current_round = Round::new(self.ante, self.current_blind);
+        self.current_round = Round::new(self.ante, self.current_blind, self.upcoming_boss, &self.config);
+    }
+
+    // Go back an ante (Hieroglyph, Petroglyph). Ante 0 is allowed, as in the base game, but no lower.
+    // The upcoming blind and boss are redrawn so they match the new ante
+    fn lower_ante(&mut self) {
+        self.ante = self.ante.saturating_sub(1);
+        self.upcoming_boss = random_boss(self.ante, None);
+        let hand_size_change = self.current_round.hand_size_change;
+        self.current_round = Round::new(self.ante, self.current_blind, self.upcoming_boss, &self.config);
+        self.current_round.hand_size_change = hand_size_change;
+    }
+
+    fn print_upcoming_boss(&self) {
+        let boss = Blind::boss(self.upcoming_boss, self.ante, &self.config);
+        println!("{} ({} points): {}", boss.name, boss.score.to_string().bold(), boss.description);
+    }
+
+    // Director's Cut allows one reroll per ante, while Retcon allows as many as you can pay for
+    fn can_reroll_boss(&self) -> bool {
+        self.player.vouchers.contains(&Voucher::Retcon)
+            || (self.player.vouchers.contains(&Voucher::DirectorsCut) && self.boss_rerolls == 0)
+    }
+
+    // Replace this ante's boss with a different one; `cost` is $0 for a Boss Tag. Returns whether it was rerolled
+    fn reroll_boss(&mut self, cost: i32) -> bool {
+        if self.player.money < cost {
+            println!("Not enough money to reroll the boss!");
+            return false;
+        }
+        self.player.money -= cost;
+        self.upcoming_boss = random_boss(self.ante, Some(self.upcoming_boss));
+        if self.current_blind == BlindType::Boss {
+            self.current_round = Round::new(self.ante, BlindType::Boss, self.upcoming_boss, &self.config);
+        }
+        println!("The boss is now {}", self.upcoming_boss.bold());
+        true
+    }
+
+    // Blind select screen: play the upcoming blind, or skip a Small or Big blind for its tag
+    fn select_blind(&mut self) {
+        loop {
+            println!("\n=== Ante {} - Upcoming: {} ===", self.ante, self.current_blind);
+            println!("Target: {} points", self.current_round.blind.score.to_string().bold());
+            if let Some(boss_ability) = &self.current_round.blind.boss_ability {
+                println!("{}: {}", boss_ability.name(), boss_ability.description());
+            }
+            println!("Reward: {}", format!("${}", self.get_blind_bounty()).yellow());
+            if let Some(tag) = self.current_round.skip_tag {
+                println!("Skip reward: {} ({})", tag, tag.description());
+            }
+            if self.current_blind != BlindType::Boss {
+                print!("\nUp next: ");
+                self.print_upcoming_boss();
+            }
+            if !self.tags.is_empty() {
+                let waiting: Vec<&str> = self.tags.iter().map(|tag| tag.name()).collect();
+                println!("Tags waiting: {}", waiting.join(", "));
+            }
+
+            match self.current_round.skip_tag {
+                Some(_) => println!("\np to play this blind, s to skip it"),
+                None => println!("\np to play this blind"),
+            }
+            if self.can_reroll_boss() {
+                println!("rb to reroll the boss blind ({})", "$10".yellow());
+            }
+            print!("> ");
+            io::stdout().flush().unwrap();
+            let mut input = String::new();
+            io::stdin().read_line(&mut input).unwrap();
+
+            match (input.trim().to_lowercase().as_str(), self.current_round.skip_tag) {
+                ("p", _) => break,
+                ("rb", _) if self.can_reroll_boss() => {
+                    if self.reroll_boss(10) {
+                        self.boss_rerolls += 1;
+                    }
+                },
+                ("s", Some(tag)) => {
+                    println!("Skipped the {}", self.current_blind);
+                    pause_after_print(400);
+                    self.blinds_skipped += 1;
+                    for joker in self.player.active_jokers_mut() {
+                        joker.ability.on_blind_skipped();
+                    }
+                    self.gain_tag(tag);
+                    self.next_round();
+                },
+                _ => println!("Invalid input! Please try again."),
+            }
+        }
+
+        self.start_round();
+    }
+
+    // Set up the player for the blind that was just selected, letting the boss have its say
+    fn start_round(&mut self) {
+        if let Some(boss_ability) = &self.current_round.blind.boss_ability {
+            self.change_round_hand_size(boss_ability.hand_size_change());
+        }
         self.player.start_round();
+        for joker in self.player.active_jokers_mut() {
+            joker.ability.on_blind_selected();
+        }
+        self.deal_hand(DealContext::FirstHand);
+        if let Some(boss_ability) = &mut self.current_round.blind.boss_ability {
+            boss_ability.on_round_start(&mut self.player, &self.ante_history);
+        }
+        self.trigger_tags(TagTiming::RoundStart);
+    }
+
+    // Change hand size until the end of the current round (never dropping below 1)
+    fn change_round_hand_size(&mut self, delta: i8) {
+        let new_size = (self.player.max_cards_in_hand as i8 + delta).max(1);
+        self.current_round.hand_size_change += new_size - self.player.max_cards_in_hand as i8;
+        self.player.max_cards_in_hand = new_size as u8;
+    }
+
+    // Deal up to the hand size, letting the boss blind change how many cards are drawn and turn some face-down
+    fn deal_hand(&mut self, context: DealContext) {
+        let fill_count = (self.player.max_cards_in_hand as usize).saturating_sub(self.player.cards_in_hand.len());
+        match &mut self.current_round.blind.boss_ability {
+            Some(boss_ability) => {
+                let count = boss_ability.cards_to_draw(context).unwrap_or(fill_count);
+                self.player.draw_cards(count, |card| boss_ability.is_dealt_face_down(card, context));
+            },
+            None => self.player.deal_hand(),
+        }
+    }
+
+    // Add a tag, copying it once for every Double Tag waiting
+    fn gain_tag(&mut self, tag: Tag) {
+        println!("Gained {}: {}", tag, tag.description());
+        pause_after_print(400);
+        let mut copies = 1;
+        if tag != Tag::Double {
+            copies += self.tags.iter().filter(|&&waiting| waiting == Tag::Double).count();
+            self.tags.retain(|&waiting| waiting != Tag::Double);
+        }
+        for _ in 0..copies {
+            self.tags.push(tag);
+        }
+        self.trigger_tags(TagTiming::Immediate);
+    }
+
+    // Apply every waiting tag for this moment. Tags that can't apply yet keep waiting
+    fn trigger_tags(&mut self, timing: TagTiming) {
+        let (ready, waiting): (Vec<Tag>, Vec<Tag>) = self.tags.drain(..).partition(|tag| tag.timing() == timing);
+        self.tags = waiting;
+        for tag in ready {
+            if !self.apply_tag(tag) {
+                self.tags.push(tag);
+            }
+        }
+    }
+
+    // Returns whether the tag was used up
+    fn apply_tag(&mut self, tag: Tag) -> bool {
+        let mut money = 0;
+        match tag {
+            Tag::Uncommon | Tag::Rare => {
+                let rarity = if tag == Tag::Rare { Rarity::Rare } else { Rarity::Uncommon };
+                let joker = Joker::new(JokerFactory::random_joker(rarity, &self.player.jokers));
+                self.shop.items.insert(0, ForSale { item: ShopItem::Joker(joker), free: true });
+            },
+            Tag::Foil | Tag::Holographic | Tag::Polychrome => {
+                let edition = match tag {
+                    Tag::Foil => Edition::Foil,
+                    Tag::Holographic => Edition::Holographic,
+                    _ => Edition::Polychrome,
+                };
+                let Some(slot) = self.shop.items.iter_mut().find(|slot| matches!(&slot.item, ShopItem::Joker(joker) if joker.edition.is_none())) else {
+                    return false;
+                };
+                if let ShopItem::Joker(joker) = &mut slot.item {
+                    joker.edition = Some(edition);
+                }
+                slot.free = true;
+            },
+            Tag::Investment => money = 25,
+            Tag::Voucher => self.shop.add_voucher(&self.player.vouchers),
+            Tag::Boss => {
+                self.reroll_boss(0);
+            },
+            Tag::Standard => self.open_booster_pack(BoosterPack::new(PackKind::Standard, PackSize::Mega)),
+            Tag::Charm => self.open_booster_pack(BoosterPack::new(PackKind::Arcana, PackSize::Mega)),
+            Tag::Meteor => self.open_booster_pack(BoosterPack::new(PackKind::Celestial, PackSize::Mega)),
+            Tag::Buffoon => self.open_booster_pack(BoosterPack::new(PackKind::Buffoon, PackSize::Mega)),
+            Tag::Ethereal => self.open_booster_pack(BoosterPack::new(PackKind::Spectral, PackSize::Normal)),
+            Tag::Double => return false,
+            Tag::Juggle => {
+                self.change_round_hand_size(3);
+                self.deal_hand(DealContext::FirstHand);
+            },
+            Tag::D6 => self.shop.reroll_cost = 0,
+            Tag::Coupon => {
+                self.shop.items.iter_mut().for_each(|slot| slot.free = true);
+                self.shop.boosters.iter_mut().for_each(|slot| slot.free = true);
+            },
+            Tag::Economy => money = self.player.money.clamp(0, 40),
+            Tag::Speed => money = 5 * self.blinds_skipped as i32,
+            Tag::Handy => money = self.player.hand_levels.total_played() as i32,
+            Tag::Garbage => money = self.unused_discards as i32,
+            Tag::Orbital(hand) => self.player.hand_levels.level_up(&hand, 3),
+        }
+
+        if money > 0 {
+            self.player.money += money;
+            println!("{}: {}", tag, format!("+${}", money).yellow());
+        } else {
+            println!("{} activated!", tag);
+        }
+        pause_after_print(400);
+        true
     }
 
     // Return values:
@@ -334,7 +924,22 @@ impl GameManager {
             println!("None");
         }
         for (i, joker) in self.player.jokers.iter().enumerate() {
-            println!("({}) [{}]: {}", i, joker.name(), joker.description());
+            if joker.face_down {
+                println!("({}) [{}]", i, "???".dimmed());
+            } else if joker.disabled {
+                println!("({}) [{}] {}: {}", i, joker.name(), "(disabled)".red(), joker.description());
+            } else {
+                println!("({}) [{}]: {}", i, joker.name(), joker.description());
+            }
+        }
+
+        // Print consumables and their descriptions
+        println!("\nConsumables ({}/{}):", self.player.consumables.len(), self.player.max_consumables);
+        if self.player.consumables.is_empty() {
+            println!("None");
+        }
+        for (i, consumable) in self.player.consumables.iter().enumerate() {
+            println!("({}) [{} - {}]: {}", i, consumable.name(), consumable.kind(), consumable.description());
         }
 
         // Print the cards in the player's hand (plus indices for selection)
@@ -343,9 +948,18 @@ impl GameManager {
         
         // Make sure cards are sorted before displaying
         self.player.sort_cards_in_hand();
+        let forced_card = match &mut self.current_round.blind.boss_ability {
+            Some(boss_ability) => boss_ability.forced_card(&self.player.cards_in_hand),
+            None => None,
+        };
         
         for (i, card) in self.player.cards_in_hand.iter().enumerate() {
-            println!("[{}] {}", i, card);
+            let forced = if forced_card == Some(i) { " (forced)".blue().to_string() } else { String::new() };
+            if card.face_down {
+                println!("[{}] {}{}", i, "???".dimmed(), forced);
+            } else {
+                println!("[{}] {}{}", i, card, forced);
+            }
             pause_after_print(100);
         }
         pause_after_print(300);
@@ -362,8 +976,10 @@ impl GameManager {
         
         // Prompt player to select cards and action
         println!("\nSelect cards (comma-separated indices) and action:");
-        println!("d for discard, p for play, s to toggle sort method");
+        println!("d for discard, p for play, s to toggle sort method, h to view poker hand levels");
         println!("j for joker move (format: 'j 2,0' to move joker from position 2 to position 0)");
+        println!("c to use a consumable (format: 'c 0' or 'c 0 1,2' to use consumable 0 on cards 1 and 2)");
+        println!("x to sell a joker (format: 'x 0')");
         println!("Example: '0,1,2,3,4 p' to play the first 5 cards");
         
         // Get user input
@@ -384,6 +1000,44 @@ impl GameManager {
             return 2; // Continue the game
         }
         
+        // Handle poker hand levels command
+        if parts.len() == 1 && (parts[0] == "h" || parts[0] == "H") {
+            self.player.hand_levels.print();
+            pause_after_print(2000);
+            return 2;
+        }
+        
+        // Handle use consumable command
+        if (parts.len() == 2 || parts.len() == 3) && (parts[0] == "c" || parts[0] == "C") {
+            let selected: Vec<usize> = parts.get(2)
+                .map(|s| s.split(',').filter_map(|s| s.parse::<usize>().ok()).collect())
+                .unwrap_or_default();
+            match parts[1].parse::<usize>() {
+                Ok(index) => {
+                    self.player.use_consumable(index, &selected);
+                },
+                Err(_) => println!("Invalid consumable index!"),
+            }
+            pause_after_print(1000);
+            return 2;
+        }
+        
+        // Handle sell joker command
+        if parts.len() == 2 && (parts[0] == "x" || parts[0] == "X") {
+            match parts[1].parse::<usize>() {
+                Ok(index) => {
+                    if self.player.sell_joker(index) {
+                        if let Some(boss_ability) = &mut self.current_round.blind.boss_ability {
+                            boss_ability.on_joker_sold();
+                        }
+                    }
+                },
+                Err(_) => println!("Invalid joker index!"),
+            }
+            pause_after_print(1000);
+            return 2;
+        }
+        
         // Handle joker swap command
         if parts.len() == 2 && (parts[0] == "j" || parts[0] == "J") {
             let indices: Vec<usize> = parts[1]
@@ -408,11 +1062,18 @@ impl GameManager {
         
         // Parse card indices
         let indices_str = parts[0];
-        let indices: Vec<usize> = indices_str
+        let mut indices: Vec<usize> = indices_str
             .split(',')
             .filter_map(|s| s.parse::<usize>().ok())
             .collect();
         
+        // A forced card is always part of the selection
+        if let Some(forced) = forced_card {
+            if !indices.contains(&forced) {
+                indices.push(forced);
+            }
+        }
+        
         // Check if indices are valid
         if indices.is_empty() || indices.len() > 5 {
             println!("You must select between 1 and 5 cards!");
@@ -439,9 +1100,10 @@ impl GameManager {
                     return 2;
                 }
                 
-                // Discard selected cards
-                self.player.discard_cards(&indices, true);
+                // Discard selected cards and deal replacements
+                self.player.discard_cards(&indices, true, false);
                 self.player.discards -= 1;
+                self.deal_hand(DealContext::AfterDiscard);
             },
             "p" | "P" => {
                 // Decrement hands counter
@@ -452,41 +1114,79 @@ impl GameManager {
                 pause_after_print(300);
                 let mut played_cards = Vec::new();
                 for &idx in &indices {
-                    let card = &self.player.cards_in_hand[idx];
+                    // Face-down cards are revealed once played
+                    let mut card = self.player.cards_in_hand[idx].clone();
+                    card.face_down = false;
                     println!("{}", card);
                     pause_after_print(200);
-                    played_cards.push(card.clone());
+                    played_cards.push(card);
                 }
                 pause_after_print(500);
                 
+                // Cards that stay in hand can still trigger (e.g. Steel cards)
+                let held_cards: Vec<Card> = self.player.cards_in_hand.iter()
+                    .enumerate()
+                    .filter(|(i, _)| !indices.contains(i))
+                    .map(|(_, card)| card.clone())
+                    .collect();
+                
                 // Determine poker hand
                 let (hand_type, scoring_card_indeces) = determine_poker_hand(&played_cards);
                 println!("\nHand type: {}", hand_type);
                 pause_after_print(500);
+                self.current_round.last_hand_played = Some(hand_type);
                 
-                // Calculate score for this hand
-                let (chips, mult) = self.calculate_hand_score(&played_cards, &hand_type, &scoring_card_indeces);
+                // Calculate score for this hand, unless the boss blind won't allow it
+                let hand_check = match &self.current_round.blind.boss_ability {
+                    Some(boss_ability) => boss_ability.check_hand(&played_cards, &hand_type),
+                    None => Ok(()),
+                };
+                let (chips, mult, round_score) = match hand_check {
+                    Ok(()) => {
+                        // Hands the boss rejects don't count as played
+                        self.player.hand_levels.record_play(&hand_type);
+                        if let Some(boss_ability) = &mut self.current_round.blind.boss_ability {
+                            boss_ability.before_score(&mut self.player, &hand_type);
+                        }
+                        self.calculate_hand_score(&played_cards, &held_cards, &hand_type, &scoring_card_indeces)
+                    },
+                    Err(reason) => {
+                        println!("{}: {}", "Not allowed!".red().bold(), reason);
+                        pause_after_print(1000);
+                        (0.0, 0.0, BigNum::ZERO)
+                    },
+                };
                 
                 // Add to total score
-                let round_score = chips * mult;
-                println!("Round score: {} x {} = {}", chips.to_string().cyan(), mult.to_string().red(), round_score.to_string().bold());
+                println!("Round score: {} x {} = {}", format_number(chips).cyan(), format_number(mult).red(), round_score.to_string().bold());
                 self.current_round.score += round_score;
                 pause_after_print(1000);
                 println!("Total score: {}", self.current_round.score.to_string().bold());
                 pause_after_print(2000);
                 
                 // Remove played cards from hand
-                self.player.discard_cards(&indices, false);
+                self.player.remove_cards(&indices, false);
+                self.ante_history.cards_played.extend_from_slice(&played_cards);
+                if let Some(boss_ability) = &mut self.current_round.blind.boss_ability {
+                    boss_ability.after_play(&mut self.player, &played_cards, &hand_type);
+                }
                 
                 // Check if round is complete
                 if self.current_round.score >= self.current_round.blind.score {
                     println!("\nCongratulations! You've beaten the {}!", self.current_blind);
+                    if let Some(boss_ability) = &mut self.current_round.blind.boss_ability {
+                        boss_ability.on_round_end(&mut self.player);
+                    }
+                    self.trigger_held_card_seals();
                     self.award_round_rewards();
                     return 0;
                 } else if self.player.hands == 0 {
                     println!("\nYou've run out of hands! Game over.");
                     return 1;
                 }
+                
+                // Deal new cards to replace the played ones
+                self.deal_hand(DealContext::AfterPlay);
             },
             _ => {
                 println!("Invalid action! Use 'd' for discard or 'p' for play.");
@@ -496,102 +1196,75 @@ impl GameManager {
         return 2;
     }
 
-    fn play_round(&mut self) {
-        if let turn_status = self.take_turn() {
-            match turn_status {
-                0 => {
-                    println!("You win!");
-                    return;
-                },
-                1 => {
-                    println!("You lose!");
-                    return;
-                },
-                _ => {
-                    self.play_round();
-                }
-            }
+    // Returns whether the round was won
+    fn play_round(&mut self) -> bool {
+        match self.take_turn() {
+            0 => {
+                println!("You win!");
+                true
+            },
+            1 => {
+                println!("You lose!");
+                false
+            },
+            _ => self.play_round(),
         }
     }
     
-    // Helper method to calculate the score for a hand. Returns (Chips, Mult)
-    fn calculate_hand_score(&self, cards: &[Card], hand_type: &PokerHand, scoring_card_indeces: &Vec<usize>) -> (u64, u64) {
-        // Get base chips and mult
-        let (mut chips, mut mult) = match hand_type {
-            PokerHand::FlushFive => (160, 16),
-            PokerHand::FlushHouse => (140, 14),
-            PokerHand::FiveOfAKind => (120, 12),
-            PokerHand::StraightFlush => (100, 8),
-            PokerHand::FourOfAKind => (60, 7),
-            PokerHand::FullHouse => (40, 4),
-            PokerHand::Flush => (35, 4),
-            PokerHand::Straight => (30, 4),
-            PokerHand::ThreeOfAKind => (30, 3),
-            PokerHand::TwoPair => (20, 2),
-            PokerHand::Pair => (10, 2),
-            PokerHand::HighCard => (5, 1),
+    // Helper method to calculate the score for a hand. Returns (Chips, Mult, Score)
+    fn calculate_hand_score(&mut self, cards: &[Card], held_cards: &[Card], hand_type: &PokerHand, scoring_card_indeces: &[usize]) -> (f64, f64, BigNum) {
+        // Scaling jokers count the hand before it scores
+        for joker in self.player.jokers.iter_mut().filter(|joker| joker.is_active()) {
+            joker.ability.on_hand_played(cards, scoring_card_indeces, hand_type, &self.player.hand_levels);
+        }
+
+        let context = ScoringContext {
+            hand_levels: &self.player.hand_levels,
+            jokers: &self.player.jokers,
+            held_cards,
+            boss: self.current_round.blind.boss_ability.as_deref(),
+            consumables: &self.player.consumables,
+            vouchers: &self.player.vouchers,
         };
-        println!("{} gives {} x {}", hand_type, chips.to_string().cyan(), mult.to_string().red());
-        pause_after_print(400);
+        let result = scoring::score_hand(&context, cards, hand_type, scoring_card_indeces, &mut rand::thread_rng());
 
-        // Add points for scoring cards
-        for &i in scoring_card_indeces.iter() {
-            // Score face value
-            let card = &cards[i];
-            // Check if the card is debuffed by the boss blind
-            let is_debuffed = if let Some(boss_ability) = &self.current_round.blind.boss_ability {
-                let debuffed = boss_ability.is_card_debuffed(card);
-                debuffed
-            } else {
-                false
-            };
-            
-            if !is_debuffed {
-                let card_score = match card.rank.as_str() {
-                    "A" => 11,
-                    "K" => 10,
-                    "Q" => 10,
-                    "J" => 10,
-                    _ => card.rank.parse::<u64>().unwrap()
-                };
-                println!("{} scores {}", card, card_score.to_string().cyan());
-                pause_after_print(400);
-                chips += card_score;
-                // Score any bonuses from jokers with ON SCORE abilities
-                for joker in &self.player.jokers {
-                    joker.on_score(card, &mut chips, &mut mult);
-                }
-            } else {
-                println!("{} scores {} (debuffed)", card, "0".cyan());
-                pause_after_print(400);
-            }
+        // Play out everything that happened, one step at a time
+        for event in &result.events {
+            println!("{}", event);
+            pause_after_print(400);
         }
 
-        // Score any bonuses from jokers with END OF ROUND abilities
-        for joker in &self.player.jokers {
-            joker.end_of_round(&mut chips, &mut mult, cards, scoring_card_indeces);
+        for card in &result.shattered {
+            self.player.remove_card_from_deck(card);
         }
+        self.player.money += result.money;
+        for joker in self.player.active_jokers_mut() {
+            joker.ability.after_hand_scored(&result.events);
+        }
+        self.player.remove_used_up_jokers();
 
-        return (chips, mult);
+        (result.chips, result.mult, result.score)
     }
 
-    fn manage_jokers(&mut self, new_joker_name: &str) {
+    // Give the player a new joker, asking them to replace one if they're at capacity. Hands the joker back if it was refused
+    fn manage_jokers(&mut self, new_joker: Joker) -> Result<(), Joker> {
+        let new_joker_name = new_joker.name().to_string();
         // Check if player has reached max jokers
-        if self.player.jokers.len() >= self.player.max_jokers as usize {
+        if new_joker.takes_slot() && !self.player.has_joker_slot() {
             println!("\nYou've reached your maximum joker capacity ({})!", self.player.max_jokers);
             println!("New joker available: {}", new_joker_name);
-            
-            // Create the new joker to show its description
-            let new_joker = JokerFactory::create_joker(new_joker_name);
             println!("Description: {}", new_joker.description());
             
+            // Eternal jokers can't be replaced, so they aren't offered
             println!("\nYour current jokers:");
-            for (i, joker) in self.player.jokers.iter().enumerate() {
+            for (i, joker) in self.player.jokers.iter().enumerate().filter(|(_, joker)| !joker.eternal) {
                 println!("[{}] {}: {}", i, joker.name(), joker.description());
             }
             
             println!("\nOptions:");
-            println!("[0-{}] Replace a joker (enter the number)", self.player.jokers.len() - 1);
+            if self.player.jokers.iter().any(|joker| !joker.eternal) {
+                println!("[number] Replace a joker (enter the number)");
+            }
             println!("[r] Refuse the new joker");
             
             // Get user input
@@ -604,76 +1277,353 @@ impl GameManager {
             
             if input.to_lowercase() == "r" {
                 println!("You refused {}.", new_joker_name);
-                return;
+                return Err(new_joker);
             }
             
             // Try to parse as index
             if let Ok(index) = input.parse::<usize>() {
-                if index < self.player.jokers.len() {
+                if index < self.player.jokers.len() && self.player.jokers[index].eternal {
+                    println!("{} is Eternal and can't be replaced! Refusing the new joker.", self.player.jokers[index].name());
+                } else if index < self.player.jokers.len() {
                     // Replace the joker at the specified index
                     println!("Replacing {} with {}", self.player.jokers[index].name(), new_joker_name);
-                    self.player.jokers[index] = JokerFactory::create_joker(new_joker_name);
+                    self.player.jokers[index] = new_joker;
+                    return Ok(());
                 } else {
                     println!("Invalid index. Refusing the new joker.");
                 }
             } else {
                 println!("Invalid input. Refusing the new joker.");
             }
+            Err(new_joker)
         } else {
             // Just add the new joker since we're under the limit
             println!("New joker acquired! {}", new_joker_name);
-            self.player.jokers.push(JokerFactory::create_joker(new_joker_name));
+            self.player.jokers.push(new_joker);
+            Ok(())
+        }
+    }
+
+    // Shop phase between blinds: buy jokers and consumables, reroll, and sell jokers
+    fn visit_shop(&mut self) {
+        // The round's hand is done with; cards are dealt fresh next round
+        self.player.cards_in_hand.clear();
+        self.shop.restock(&self.player.jokers);
+        self.trigger_tags(TagTiming::ShopEnter);
+        for joker in self.player.active_jokers_mut() {
+            joker.ability.on_shop_entered();
+        }
+
+        loop {
+            println!("\n=== Shop ===");
+            println!("Money: {}", format!("${}", self.player.money).yellow().bold());
+            print!("Upcoming boss (Ante {}): ", self.ante);
+            self.print_upcoming_boss();
+            self.shop.print();
+
+            println!("\nYour jokers ({}/{}):", self.player.jokers.iter().filter(|joker| joker.takes_slot()).count(), self.player.max_jokers);
+            if self.player.jokers.is_empty() {
+                println!("None");
+            }
+            for (i, joker) in self.player.jokers.iter().enumerate() {
+                println!("({}) [{}] sells for {}: {}", i, joker.name(), format!("${}", joker.sell_value()).yellow(), joker.description());
+            }
+            println!("\nYour consumables ({}/{}):", self.player.consumables.len(), self.player.max_consumables);
+            if self.player.consumables.is_empty() {
+                println!("None");
+            }
+            for (i, consumable) in self.player.consumables.iter().enumerate() {
+                println!("({}) [{} - {}]: {}", i, consumable.name(), consumable.kind(), consumable.description());
+            }
+
+            println!("\nb to buy (format: 'b 0'), p to buy and open a booster pack (format: 'p 0')");
+            println!("v to redeem a voucher (format: 'v 0'), r to reroll, s to sell a joker (format: 's 0')");
+            println!("c to use a consumable (format: 'c 0'), n to move on to the next round");
+            if self.can_reroll_boss() {
+                println!("rb to reroll the boss blind ({})", "$10".yellow());
+            }
+            print!("> ");
+            io::stdout().flush().unwrap();
+            let mut input = String::new();
+            io::stdin().read_line(&mut input).unwrap();
+            let parts: Vec<&str> = input.split_whitespace().collect();
+            let index = parts.get(1).and_then(|s| s.parse::<usize>().ok());
+
+            match (parts.first().map(|s| s.to_lowercase()).as_deref(), index) {
+                (Some("b"), Some(index)) => self.buy_shop_item(index),
+                (Some("p"), Some(index)) => self.buy_booster_pack(index),
+                (Some("v"), index) => self.buy_voucher(index.unwrap_or(0)),
+                (Some("r"), None) => {
+                    if self.player.money < self.shop.reroll_cost {
+                        println!("Not enough money to reroll!");
+                    } else {
+                        self.player.money -= self.shop.reroll_cost;
+                        self.shop.reroll(&self.player.jokers);
+                        self.trigger_tags(TagTiming::ShopEnter);
+                    }
+                },
+                (Some("s"), Some(index)) => {
+                    self.player.sell_joker(index);
+                },
+                (Some("c"), Some(index)) => {
+                    self.player.use_consumable(index, &[]);
+                },
+                (Some("rb"), None) if self.can_reroll_boss() => {
+                    if self.reroll_boss(10) {
+                        self.boss_rerolls += 1;
+                    }
+                },
+                (Some("n"), None) => break,
+                _ => println!("Invalid input! Please try again."),
+            }
+            pause_after_print(1000);
+        }
+
+        for joker in self.player.active_jokers_mut() {
+            joker.ability.on_shop_exited();
+        }
+    }
+
+    fn buy_shop_item(&mut self, index: usize) {
+        if index >= self.shop.items.len() {
+            println!("Invalid item index!");
+            return;
+        }
+        let price = self.shop.item_price(index);
+        if self.player.money < price {
+            println!("You can't afford {}!", self.shop.items[index].item.name());
+            return;
+        }
+
+        // Items that couldn't be taken stay on the shelf
+        let ForSale { item, free } = self.shop.items.remove(index);
+        let refused = match item {
+            ShopItem::Joker(joker) => self.manage_jokers(joker).err().map(ShopItem::Joker),
+            ShopItem::Consumable(consumable) => {
+                if self.player.add_consumable(consumable.name()) {
+                    println!("Bought {}!", consumable.name());
+                    None
+                } else {
+                    Some(ShopItem::Consumable(consumable))
+                }
+            },
+            ShopItem::Card(card) => {
+                println!("{} added to your deck", card);
+                self.player.add_card_to_deck(card);
+                None
+            },
+        };
+        match refused {
+            Some(item) => self.shop.items.insert(index, ForSale { item, free }),
+            None => self.player.money -= price,
+        }
+    }
+
+    fn buy_booster_pack(&mut self, index: usize) {
+        if index >= self.shop.boosters.len() {
+            println!("Invalid booster pack index!");
+            return;
+        }
+        let pack = self.shop.boosters[index].item;
+        let price = self.shop.booster_price(index);
+        if self.player.money < price {
+            println!("You can't afford the {}!", pack);
+            return;
+        }
+        self.player.money -= price;
+        self.shop.boosters.remove(index);
+        self.open_booster_pack(pack);
+    }
+
+    fn buy_voucher(&mut self, index: usize) {
+        let Some(&voucher) = self.shop.vouchers.get(index) else {
+            println!("There's no voucher for sale there!");
+            return;
+        };
+        let price = self.shop.price(voucher.cost());
+        if self.player.money < price {
+            println!("You can't afford {}!", voucher);
+            return;
+        }
+        self.player.money -= price;
+        self.shop.vouchers.remove(index);
+        self.redeem_voucher(voucher);
+    }
+
+    // Apply a voucher's permanent effect for the rest of the run
+    fn redeem_voucher(&mut self, voucher: Voucher) {
+        println!("Redeemed {}: {}", voucher, voucher.description());
+        self.player.vouchers.push(voucher);
+        match voucher {
+            Voucher::Overstock | Voucher::OverstockPlus => self.shop.card_slots += 1,
+            Voucher::ClearanceSale => self.shop.discount = 25,
+            Voucher::Liquidation => self.shop.discount = 50,
+            Voucher::Hone => self.shop.edition_rate = 2,
+            Voucher::GlowUp => self.shop.edition_rate = 4,
+            Voucher::RerollSurplus | Voucher::RerollGlut => self.shop.discount_rerolls(2),
+            Voucher::CrystalBall => self.player.max_consumables += 1,
+            Voucher::Grabber | Voucher::NachoTong => self.player.max_hands += 1,
+            Voucher::Wasteful | Voucher::Recyclomancy => self.player.max_discards += 1,
+            Voucher::TarotMerchant => self.shop.tarot_rate = 2,
+            Voucher::TarotTycoon => self.shop.tarot_rate = 4,
+            Voucher::PlanetMerchant => self.shop.planet_rate = 2,
+            Voucher::PlanetTycoon => self.shop.planet_rate = 4,
+            Voucher::SeedMoney => self.player.interest_cap = 10,
+            Voucher::MoneyTree => self.player.interest_cap = 20,
+            Voucher::Antimatter => self.player.max_jokers += 1,
+            Voucher::MagicTrick => self.shop.sells_playing_cards = true,
+            Voucher::Illusion => self.shop.enhanced_playing_cards = true,
+            Voucher::Hieroglyph => {
+                self.lower_ante();
+                self.player.max_hands = self.player.max_hands.saturating_sub(1).max(1);
+            },
+            Voucher::Petroglyph => {
+                self.lower_ante();
+                self.player.max_discards = self.player.max_discards.saturating_sub(1);
+            },
+            Voucher::PaintBrush | Voucher::Palette => self.player.change_hand_size(1),
+            // These are checked where they apply (booster packs and scoring)
+            Voucher::OmenGlobe | Voucher::Telescope | Voucher::Observatory | Voucher::Blank
+            | Voucher::DirectorsCut | Voucher::Retcon => {},
+        }
+    }
+
+    // Show the pack's contents and let the player pick from them
+    fn open_booster_pack(&mut self, pack: BoosterPack) {
+        let mut choices = pack.generate_choices(&self.player);
+        // Tarot and Spectral cards need a hand to be used on
+        if pack.needs_hand() {
+            self.player.cards_in_hand.clear();
+            self.player.shuffle_deck();
+            self.player.deal_hand();
+        }
+
+        let mut picks_left = pack.picks();
+        while picks_left > 0 && !choices.is_empty() {
+            println!("\n=== {} === ({} pick(s) left)", pack, picks_left);
+            for (i, choice) in choices.iter().enumerate() {
+                println!("[{}] {}", i, choice);
+            }
+            if pack.needs_hand() {
+                println!("\nYour hand:");
+                for (i, card) in self.player.cards_in_hand.iter().enumerate() {
+                    println!("({}) {}", i, card);
+                }
+                println!("\nPick a card (format: '0', or '0 1,2' to use it on cards 1 and 2 in hand), s to skip");
+            } else {
+                println!("\nPick a card (format: '0'), s to skip");
+            }
+            print!("> ");
+            io::stdout().flush().unwrap();
+            let mut input = String::new();
+            io::stdin().read_line(&mut input).unwrap();
+            let parts: Vec<&str> = input.split_whitespace().collect();
+
+            if parts.first().map(|s| s.to_lowercase()).as_deref() == Some("s") {
+                break;
+            }
+            let Some(index) = parts.first().and_then(|s| s.parse::<usize>().ok()).filter(|&i| i < choices.len()) else {
+                println!("Invalid input! Please try again.");
+                continue;
+            };
+            let selected: Vec<usize> = parts
+                .get(1)
+                .map(|s| s.split(',').filter_map(|n| n.trim().parse::<usize>().ok()).collect())
+                .unwrap_or_default();
+
+            let picked = match choices.remove(index) {
+                PackChoice::Card(card) => {
+                    println!("{} added to your deck", card);
+                    self.player.add_card_to_deck(card);
+                    true
+                },
+                PackChoice::Joker(joker) => {
+                    match self.manage_jokers(joker) {
+                        Ok(()) => true,
+                        Err(joker) => {
+                            // Refused jokers go back in the pack
+                            choices.insert(index, PackChoice::Joker(joker));
+                            false
+                        },
+                    }
+                },
+                PackChoice::Consumable(consumable) => {
+                    match self.player.check_consumable_use(consumable.as_ref(), &selected) {
+                        Some(selected) => {
+                            self.player.apply_consumable(consumable, &selected);
+                            true
+                        },
+                        None => {
+                            choices.insert(index, PackChoice::Consumable(consumable));
+                            false
+                        },
+                    }
+                },
+            };
+            if picked {
+                picks_left -= 1;
+            }
+            pause_after_print(1000);
+        }
+
+        if pack.needs_hand() {
+            self.player.cards_in_hand.clear();
         }
     }
 }
 
 // Helper method to determine the poker hand type. Returns the hand type and a list of indexes of the cards that have scored
 pub fn determine_poker_hand(cards: &[Card]) -> (PokerHand, Vec<usize>) {
+    // Stone cards have no rank or suit, so determine the hand without them and let them score regardless
+    if cards.iter().any(|card| card.is_stone()) {
+        let ranked_indices: Vec<usize> = (0..cards.len()).filter(|&i| !cards[i].is_stone()).collect();
+        let stone_indices: Vec<usize> = (0..cards.len()).filter(|&i| cards[i].is_stone()).collect();
+        if ranked_indices.is_empty() {
+            return (PokerHand::HighCard, stone_indices);
+        }
+        let ranked_cards: Vec<Card> = ranked_indices.iter().map(|&i| cards[i].clone()).collect();
+        let (hand_type, scoring_indices) = determine_poker_hand(&ranked_cards);
+        let mut scoring_indices: Vec<usize> = scoring_indices.iter().map(|&i| ranked_indices[i]).collect();
+        scoring_indices.extend(stone_indices);
+        scoring_indices.sort();
+        return (hand_type, scoring_indices);
+    }
+
     // Start from the highest hand type and work down
 
-    let is_flush = cards.len() == 5 && cards.iter().all(|card| match (&card.suit, &cards[0].suit) {
-        (Suit::Spades, Suit::Spades) => true,
-        (Suit::Hearts, Suit::Hearts) => true,
-        (Suit::Clubs, Suit::Clubs) => true,
-        (Suit::Diamonds, Suit::Diamonds) => true,
-        _ => false,
-    });
+    // Wild cards count towards any suit
+    let is_flush = cards.len() == 5 && Suit::ALL.iter()
+        .any(|suit| cards.iter().all(|card| card.is_suit(suit)));
     let is_straight = {
         if cards.len() != 5 { 
             false  // can only have a straight with 5 cards
         } else {
-            let char_to_num = |c: &String| match c.as_str() {
-                "A" => 14,
-                "K" => 13, 
-                "Q" => 12,
-                "J" => 11,
-                _ => c.parse::<u8>().unwrap()
+            // Numbers should be exactly one away from each other, with the Ace either high or low (A,2,3,4,5)
+            let is_run = |mut values: Vec<u8>| {
+                values.sort();
+                values.windows(2).all(|w| w[1] - w[0] == 1)
             };
-            let mut ranks = cards.iter()
-                .map(|card| char_to_num(&card.rank))
-                .collect::<Vec<_>>();
-            ranks.sort();
-            // Numbers should be exactly one away from each other; exception is A,2,3,4,5 since A=1 or A=14
-            ranks.windows(2).all(|w| w[1] - w[0] == 1) || ranks == vec![2, 3, 4, 5, 14]
+            is_run(cards.iter().map(|card| card.rank.value()).collect())
+                || is_run(cards.iter().map(|card| card.rank.low_value()).collect())
         }
     };
 
     // For hands that use all cards, we'll use this
     let all_indices: Vec<usize> = (0..cards.len()).collect();
-    let mut rank_indices: HashMap<&String, Vec<usize>> = HashMap::new();
+    let mut rank_indices: HashMap<&Rank, Vec<usize>> = HashMap::new();
     for (i, card) in cards.iter().enumerate() {
         rank_indices.entry(&card.rank).or_insert(Vec::new()).push(i);
     }
 
     // FLUSH FIVE [7♣, 7♣, 7♣, 7♣, 7♣]
-    if cards.len() == 5 && cards.iter().all(|card| card.rank == cards[0].rank && card.suit == cards[0].suit) {
+    if is_flush && cards.iter().all(|card| card.rank == cards[0].rank) {
         return (PokerHand::FlushFive, all_indices.clone());
     }
 
     // util: `ranks` will store the count of each rank
     let mut ranks = HashMap::new();
     for card in cards {
-        *ranks.entry(card.rank.clone()).or_insert(0) += 1;
+        *ranks.entry(card.rank).or_insert(0) += 1;
     }
 
     // FLUSH HOUSE [5♠, 5♠, 5♠, 8♠, 8♠]
@@ -757,20 +1707,9 @@ pub fn determine_poker_hand(cards: &[Card]) -> (PokerHand, Vec<usize>) {
     }
 
     // HIGH CARD [A♣, 4♦, 7♥, 8♣, K♦]
-    let char_to_num = |c: &String| match c.as_str() {
-        "A" => 14,
-        "K" => 13, 
-        "Q" => 12,
-        "J" => 11,
-        _ => c.parse::<u8>().unwrap()
-    };
-    
     let mut highest_idx = 0;
-    let mut highest_val = 0;
     for (i, card) in cards.iter().enumerate() {
-        let val = char_to_num(&card.rank);
-        if val > highest_val {
-            highest_val = val;
+        if card.rank > cards[highest_idx].rank {
             highest_idx = i;
         }
     }
@@ -790,75 +1729,86 @@ pub fn determine_poker_hand(cards: &[Card]) -> (PokerHand, Vec<usize>) {
  * 6. Iterate through each card, scoring as follows:
  *   a. If the card is debuffed, skip
  *   b. Add the card's face value to "chips"
- *   c. If the card has any enhancements add those to either "chips" or "mult"
+ *   c. If the card has any enhancements add those to either "chips" or "mult" (e.g. Bonus, Mult, Glass, Lucky)
  *   d. Activate any Jokers' "ON SCORE" abilities (e.g. +Mult for suit, +Chips for rank, etc)
- * 7. After all cards have been scored, iterate through the cards left in the player's hand for cards that trigger in-hand (e.g. steel cards)
- * 8. Activate any Jokers' "END OF ROUND" abilities (e.g. x3 mult if enhanced cards, +Chips if hand is two pair, etc)
+ * 7. After all cards have been scored, iterate through the cards left in the player's hand for cards and Jokers that trigger in-hand (e.g. steel cards)
+ * 8. Activate any Jokers' "AFTER CARDS SCORED" abilities (e.g. x3 mult if enhanced cards, +Chips if hand is two pair, etc)
  * 9. Calculate hand score via "chips x mult", add to total score, check if we've won or need to keep playing and action accordingly
  */
 
+// Ask which stake to play on; anything that isn't a valid choice plays White Stake
+fn choose_stake() -> Stake {
+    println!("Choose a stake (each includes the ones above it):");
+    for (i, stake) in Stake::ALL.iter().enumerate() {
+        println!("({}) {}: {}", i, stake, stake.description());
+    }
+    print!("> ");
+    io::stdout().flush().unwrap();
+    let mut input = String::new();
+    io::stdin().read_line(&mut input).unwrap();
+    let stake = input.trim().parse::<usize>().ok()
+        .and_then(|i| Stake::ALL.get(i).copied())
+        .unwrap_or_default();
+    println!("Playing on {}", stake);
+    stake
+}
+
 fn main() {
     println!("Welcome to BalatRust! Press Enter to continue");
     let mut input = String::new();
     io::stdin().read_line(&mut input).unwrap();
 
+    let config = RunConfig::new(choose_stake());
+
     // Create player and their deck
     let mut deck = Vec::new();
-    for suit in [Suit::Spades, Suit::Hearts, Suit::Clubs, Suit::Diamonds].iter() {
-        for rank in ["2", "3", "4", "5", "6", "7", "8", "9", "10", "J", "Q", "K", "A"] {
-            deck.push(Card { suit: suit.clone(), rank: rank.to_string() });
+    for suit in Suit::ALL.iter() {
+        for rank in Rank::ALL {
+            deck.push(Card::new(suit.clone(), rank));
         }
     }
     let mut player = Player {
         money: 4,
         deck,
         jokers: Vec::new(),
+        hand_levels: HandLevels::new(),
+        consumables: Vec::new(),
+        last_consumable_used: None,
+        planets_used: Vec::new(),
+        vouchers: Vec::new(),
         current_deck: Vec::new(),
         cards_in_hand: Vec::new(),
         hands: 4,
-        discards: 3,
+        discards: config.starting_discards(),
         max_cards_in_hand: 8,
         max_hands: 4,
-        max_discards: 3,
+        max_discards: config.starting_discards(),
         max_jokers: 5,
+        max_consumables: 2,
+        interest_cap: 5,
         sort_method: SortMethod::ByRank, // Default to sorting by rank
     };
 
-    // Available jokers:
-    let mut available_jokers = vec![
-        "Joker",
-        "Greedy Joker",
-        "Lusty Joker",
-        "Wrathful Joker",
-        "Gluttonous Joker",
-        "Jolly Joker",
-        "Zany Joker",
-        "Mad Joker",
-        "Crazy Joker",
-        "Droll Joker",
-        "Sly Joker",
-        "Wily Joker",
-        "Clever Joker",
-        "Devious Joker",
-        "Crafty Joker",
-    ];
-    let mut game_manager = GameManager::new(player);
+    let mut game_manager = GameManager::new(player, config);
 
     // BEGIN GAME LOOP
     loop {
-        let game_status = game_manager.play_round();
+        game_manager.select_blind();
+        let won = game_manager.play_round();
+        if !won {
+            break;  // game over
+        }
         
         // Check for quit
-        println!("\nPress Enter to continue or type 'q' to exit");
+        println!("\nPress Enter to continue to the shop or type 'q' to exit");
         let mut input = String::new();
         io::stdin().read_line(&mut input).unwrap();
         if input.trim().to_lowercase() == "q" {
             break;
         } else {
+            // Move on first, so the shop can show what's coming up (and restock vouchers after a boss)
             game_manager.next_round();
-            let mut rng = rand::thread_rng();
-            let index = rng.gen_range(0..available_jokers.len());
-            game_manager.manage_jokers(available_jokers.remove(index));
+            game_manager.visit_shop();
         }
     }
 }
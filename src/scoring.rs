@@ -0,0 +1,332 @@
+use colored::*;
+use rand::Rng;
+
+use crate::PokerHand;
+use crate::bignum::BigNum;
+use crate::blinds::BossBlindAbility;
+use crate::cards::{Card, Edition, Enhancement, Seal};
+use crate::consumables::{planet_for_hand, ConsumableAbility};
+use crate::hands::HandLevels;
+use crate::jokers::Joker;
+use crate::vouchers::Voucher;
+
+// A single change to the hand's score. Effects apply in the order they trigger, so an
+// XMult after a +Mult multiplies it too
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Effect {
+    Chips(f64),
+    Mult(f64),
+    XMult(f64),
+    Money(i32),
+}
+
+impl Effect {
+    fn apply(&self, chips: &mut f64, mult: &mut f64, money: &mut i32) {
+        match self {
+            Effect::Chips(amount) => *chips += amount,
+            Effect::Mult(amount) => *mult += amount,
+            Effect::XMult(factor) => *mult *= factor,
+            Effect::Money(amount) => *money += amount,
+        }
+    }
+}
+
+// Chips and mult rounded to 2 decimal places for display, without trailing zeros
+pub fn format_number(value: f64) -> String {
+    ((value * 100.0).round() / 100.0).to_string()
+}
+
+impl std::fmt::Display for Effect {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Effect::Chips(amount) => write!(f, "{} chips", format!("+{}", format_number(*amount)).cyan()),
+            Effect::Mult(amount) => write!(f, "{} mult", format!("+{}", format_number(*amount)).red()),
+            Effect::XMult(factor) => write!(f, "{} mult", format!("x{}", format_number(*factor)).red()),
+            Effect::Money(amount) => write!(f, "{}", format!("+${}", amount).yellow()),
+        }
+    }
+}
+
+// Something that happened while scoring a hand, in the order it happened
+pub enum ScoreEvent {
+    HandBase { hand: PokerHand, level: u32, chips: f64, mult: f64 },
+    BaseChanged { source: String, chips: f64, mult: f64 },  // boss blinds such as The Flint
+    CardScored { card: Card, chips: u64 },
+    CardDebuffed { card: Card },
+    Retriggered { card: Card, source: String },
+    EnhancementTriggered { enhancement: Enhancement, effect: Effect },  // on a scored card
+    Triggered { source: String, effect: Effect },  // editions, seals, held cards and vouchers
+    JokerTriggered { joker: String, effect: Effect },
+    CardShattered { card: Card },
+}
+
+impl std::fmt::Display for ScoreEvent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ScoreEvent::HandBase { hand, level, chips, mult } => write!(f, "{} (lvl.{}) gives {} x {}", hand, level, format_number(*chips).cyan(), format_number(*mult).red()),
+            ScoreEvent::BaseChanged { source, chips, mult } => write!(f, "{}: base is now {} x {}", source, format_number(*chips).cyan(), format_number(*mult).red()),
+            ScoreEvent::CardScored { card, chips } => write!(f, "{} scores {}", card, chips.to_string().cyan()),
+            ScoreEvent::CardDebuffed { card } => write!(f, "{} scores {} (debuffed)", card, "0".cyan()),
+            ScoreEvent::Retriggered { card, source } => write!(f, "{} ({}): Again!", card, source),
+            ScoreEvent::EnhancementTriggered { enhancement, effect } => write!(f, "{}: {}", enhancement, effect),
+            ScoreEvent::Triggered { source, effect } => write!(f, "{}: {}", source, effect),
+            ScoreEvent::JokerTriggered { joker, effect } => write!(f, "{}: {}", joker, effect),
+            ScoreEvent::CardShattered { card } => write!(f, "{} shattered!", card),
+        }
+    }
+}
+
+// Everything about the run that a hand's score depends on, besides the cards played
+pub struct ScoringContext<'a> {
+    pub hand_levels: &'a HandLevels,
+    pub jokers: &'a [Joker],
+    pub held_cards: &'a [Card],
+    pub boss: Option<&'a dyn BossBlindAbility>,
+    pub consumables: &'a [Box<dyn ConsumableAbility>],
+    pub vouchers: &'a [Voucher],
+}
+
+pub struct ScoreResult {
+    pub chips: f64,
+    pub mult: f64,
+    pub score: BigNum,
+    pub money: i32,  // earned while scoring (Lucky cards, Gold Seals)
+    pub shattered: Vec<Card>,  // Glass cards to destroy
+    pub events: Vec<ScoreEvent>,
+}
+
+// Score a played hand without printing or changing anything, so it can be shown afterwards or run headlessly
+pub fn score_hand(context: &ScoringContext, cards: &[Card], hand_type: &PokerHand, scoring_card_indeces: &[usize], rng: &mut impl Rng) -> ScoreResult {
+    let mut events = Vec::new();
+    let mut money = 0;
+    let mut shattered = Vec::new();
+
+    // Get base chips and mult for the hand's current level
+    let (base_chips, base_mult) = context.hand_levels.score(hand_type);
+    let (mut chips, mut mult) = (base_chips as f64, base_mult as f64);
+    events.push(ScoreEvent::HandBase { hand: *hand_type, level: context.hand_levels.get(hand_type).level, chips, mult });
+    if let Some(boss) = context.boss {
+        let base = (chips, mult);
+        boss.modify_base_score(&mut chips, &mut mult);
+        if (chips, mult) != base {
+            events.push(ScoreEvent::BaseChanged { source: boss.name().to_string(), chips, mult });
+        }
+    }
+    let is_debuffed = |card: &Card| context.boss.is_some_and(|boss| boss.is_card_debuffed(card));
+    let active_jokers = || context.jokers.iter().filter(|joker| joker.is_active());
+
+    // Score any bonuses from jokers with ON PLAY abilities, before any cards score
+    for joker in active_jokers() {
+        if let Some(effect) = joker.ability.on_play(cards, scoring_card_indeces) {
+            effect.apply(&mut chips, &mut mult, &mut money);
+            events.push(ScoreEvent::JokerTriggered { joker: joker.name().to_string(), effect });
+        }
+    }
+
+    // Add points for scoring cards
+    for &i in scoring_card_indeces {
+        let card = &cards[i];
+        if is_debuffed(card) {
+            events.push(ScoreEvent::CardDebuffed { card: card.clone() });
+            continue;
+        }
+
+        // Red Seals score the card a second time
+        let triggers = if card.seal == Some(Seal::Red) { 2 } else { 1 };
+        for trigger in 0..triggers {
+            if trigger > 0 {
+                events.push(ScoreEvent::Retriggered { card: card.clone(), source: Seal::Red.to_string() });
+            }
+            let card_score = if card.is_stone() {
+                50  // Stone cards replace their face value entirely
+            } else {
+                card.rank.chips()
+            };
+            chips += card_score as f64;
+            events.push(ScoreEvent::CardScored { card: card.clone(), chips: card_score });
+
+            let mut card_effects = Vec::new();
+
+            // Apply the card's enhancement
+            let mut enhancement_effects = Vec::new();
+            match card.enhancement {
+                Some(Enhancement::Bonus) => enhancement_effects.push(Effect::Chips(30.0)),
+                Some(Enhancement::Mult) => enhancement_effects.push(Effect::Mult(4.0)),
+                Some(Enhancement::Glass) => enhancement_effects.push(Effect::XMult(2.0)),
+                Some(Enhancement::Lucky) => {
+                    if rng.gen_range(0..5) == 0 {
+                        enhancement_effects.push(Effect::Mult(20.0));
+                    }
+                    if rng.gen_range(0..15) == 0 {
+                        enhancement_effects.push(Effect::Money(20));
+                    }
+                },
+                _ => {},
+            }
+            if let Some(enhancement) = card.enhancement {
+                card_effects.extend(enhancement_effects.into_iter().map(|effect| ScoreEvent::EnhancementTriggered { enhancement, effect }));
+            }
+
+            // Apply the card's edition
+            if let Some(effect) = card.edition.as_ref().and_then(edition_effect) {
+                card_effects.push(ScoreEvent::Triggered { source: format!("{} ({})", card, card.edition.unwrap()), effect });
+            }
+
+            // Gold Seals pay out every time the card scores
+            if card.seal == Some(Seal::Gold) {
+                card_effects.push(ScoreEvent::Triggered { source: format!("{} ({})", card, Seal::Gold), effect: Effect::Money(3) });
+            }
+
+            for event in card_effects {
+                if let ScoreEvent::EnhancementTriggered { effect, .. } | ScoreEvent::Triggered { effect, .. } = &event {
+                    effect.apply(&mut chips, &mut mult, &mut money);
+                }
+                events.push(event);
+            }
+
+            // Score any bonuses from jokers with ON SCORE abilities
+            for joker in active_jokers() {
+                if let Some(effect) = joker.ability.on_score(card) {
+                    effect.apply(&mut chips, &mut mult, &mut money);
+                    events.push(ScoreEvent::JokerTriggered { joker: joker.name().to_string(), effect });
+                }
+            }
+        }
+
+        // Glass cards may shatter once they've scored
+        if card.enhancement == Some(Enhancement::Glass) && rng.gen_range(0..4) == 0 {
+            shattered.push(card.clone());
+        }
+    }
+
+    // Score any cards held in hand, then any jokers with HELD IN HAND abilities for each of them
+    for card in context.held_cards.iter().filter(|card| !is_debuffed(card)) {
+        if card.enhancement == Some(Enhancement::Steel) {
+            let effect = Effect::XMult(1.5);
+            effect.apply(&mut chips, &mut mult, &mut money);
+            events.push(ScoreEvent::Triggered { source: format!("{} held in hand", card), effect });
+        }
+        for joker in active_jokers() {
+            if let Some(effect) = joker.ability.on_held_in_hand(card) {
+                effect.apply(&mut chips, &mut mult, &mut money);
+                events.push(ScoreEvent::JokerTriggered { joker: joker.name().to_string(), effect });
+            }
+        }
+    }
+
+    // Score any bonuses from jokers with AFTER CARDS SCORED abilities, left to right.
+    // Foil and Holographic jokers add their bonus before their ability, Polychrome multiplies after it
+    for joker in active_jokers() {
+        let edition = joker.edition.as_ref().and_then(|edition| edition_effect(edition).map(|effect| (edition, effect)));
+        let edition_event = |edition: &Edition, effect| ScoreEvent::Triggered { source: format!("{} ({})", joker.name(), edition), effect };
+        if let Some((edition, effect)) = edition.filter(|(_, effect)| !matches!(effect, Effect::XMult(_))) {
+            effect.apply(&mut chips, &mut mult, &mut money);
+            events.push(edition_event(edition, effect));
+        }
+        if let Some(effect) = joker.ability.after_cards_scored(cards, scoring_card_indeces) {
+            effect.apply(&mut chips, &mut mult, &mut money);
+            events.push(ScoreEvent::JokerTriggered { joker: joker.name().to_string(), effect });
+        }
+        if let Some((edition, effect)) = edition.filter(|(_, effect)| matches!(effect, Effect::XMult(_))) {
+            effect.apply(&mut chips, &mut mult, &mut money);
+            events.push(edition_event(edition, effect));
+        }
+    }
+
+    // Observatory: held planet cards multiply the hand they level up
+    if context.vouchers.contains(&Voucher::Observatory) {
+        for consumable in context.consumables {
+            if consumable.name() == planet_for_hand(hand_type) {
+                let effect = Effect::XMult(1.5);
+                effect.apply(&mut chips, &mut mult, &mut money);
+                events.push(ScoreEvent::Triggered { source: consumable.name().to_string(), effect });
+            }
+        }
+    }
+
+    // Glass cards that shattered are destroyed after scoring
+    for card in &shattered {
+        events.push(ScoreEvent::CardShattered { card: card.clone() });
+    }
+
+    ScoreResult {
+        chips,
+        mult,
+        score: BigNum::from_f64(chips) * BigNum::from_f64(mult),
+        money,
+        shattered,
+        events,
+    }
+}
+
+fn edition_effect(edition: &Edition) -> Option<Effect> {
+    match edition {
+        Edition::Foil => Some(Effect::Chips(50.0)),
+        Edition::Holographic => Some(Effect::Mult(10.0)),
+        Edition::Polychrome => Some(Effect::XMult(1.5)),
+        Edition::Negative => None,  // no scoring effect
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+
+    use super::*;
+    use crate::blinds::TheClub;
+
+    fn cards(notation: &[&str]) -> Vec<Card> {
+        notation.iter().map(|card| card.parse().unwrap()).collect()
+    }
+
+    fn score(cards: &[Card], hand_type: PokerHand, jokers: &[Joker], boss: Option<&dyn BossBlindAbility>) -> ScoreResult {
+        let hand_levels = HandLevels::new();
+        let context = ScoringContext {
+            hand_levels: &hand_levels,
+            jokers,
+            held_cards: &[],
+            boss,
+            consumables: &[],
+            vouchers: &[],
+        };
+        let scoring_card_indeces: Vec<usize> = (0..cards.len()).collect();
+        score_hand(&context, cards, &hand_type, &scoring_card_indeces, &mut StdRng::seed_from_u64(0))
+    }
+
+    #[test]
+    fn xmult_applies_in_trigger_order() {
+        // Pair gives 10 x 2, the Glass King doubles it to 4, Joker adds 4, then its Polychrome multiplies the 8
+        let mut hand = cards(&["Kh", "Kd"]);
+        hand[0].enhancement = Some(Enhancement::Glass);
+        let mut joker = Joker::new("Joker");
+        joker.edition = Some(Edition::Polychrome);
+
+        let result = score(&hand, PokerHand::Pair, &[joker], None);
+        assert_eq!(result.chips, 30.0);
+        assert_eq!(result.mult, 12.0);
+        assert_eq!(result.score.to_string(), "360");
+    }
+
+    #[test]
+    fn debuffed_cards_score_nothing() {
+        let hand = cards(&["Kc", "Kh"]);
+        let boss = TheClub;
+
+        let result = score(&hand, PokerHand::Pair, &[], Some(&boss));
+        assert_eq!(result.chips, 20.0);
+        assert_eq!(result.mult, 2.0);
+        assert!(result.events.iter().any(|event| matches!(event, ScoreEvent::CardDebuffed { card } if *card == hand[0])));
+    }
+
+    #[test]
+    fn red_seal_scores_card_twice() {
+        let mut hand = cards(&["Ah"]);
+        hand[0].seal = Some(Seal::Red);
+
+        let result = score(&hand, PokerHand::HighCard, &[], None);
+        assert_eq!(result.chips, 5.0 + 11.0 * 2.0);
+        assert_eq!(result.mult, 1.0);
+        assert_eq!(result.events.iter().filter(|event| matches!(event, ScoreEvent::Retriggered { .. })).count(), 1);
+    }
+}
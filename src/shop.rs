@@ -0,0 +1,273 @@
+use colored::*;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use crate::boosters::BoosterPack;
+use crate::cards::{Card, Edition};
+use crate::consumables::{ConsumableAbility, ConsumableFactory, PLANETS, TAROTS};
+use crate::jokers::{Joker, JokerFactory, Rarity};
+use crate::stakes::RunConfig;
+use crate::vouchers::Voucher;
+
+pub enum ShopItem {
+    Joker(Joker),
+    Consumable(Box<dyn ConsumableAbility>),
+    Card(Card),  // only sold once Magic Trick is redeemed
+}
+
+impl ShopItem {
+    pub fn name(&self) -> &str {
+        match self {
+            ShopItem::Joker(joker) => joker.name(),
+            ShopItem::Consumable(consumable) => consumable.name(),
+            ShopItem::Card(_) => "Playing Card",
+        }
+    }
+
+    pub fn description(&self) -> String {
+        match self {
+            ShopItem::Joker(joker) => joker.description(),
+            ShopItem::Consumable(consumable) => consumable.description(),
+            ShopItem::Card(_) => "Add this card to your deck".to_string(),
+        }
+    }
+
+    pub fn price(&self) -> i32 {
+        match self {
+            ShopItem::Joker(joker) => joker.cost(),
+            ShopItem::Consumable(consumable) => consumable.kind().cost(),
+            ShopItem::Card(card) => match card.edition {
+                Some(Edition::Foil) => 3,
+                Some(Edition::Holographic) => 4,
+                Some(Edition::Polychrome) | Some(Edition::Negative) => 6,
+                None => 1,
+            },
+        }
+    }
+}
+
+impl std::fmt::Display for ShopItem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ShopItem::Joker(joker) => write!(f, "[Joker - {}] {}", joker.ability.rarity(), joker.name()),
+            ShopItem::Consumable(consumable) => write!(f, "[{}] {}", consumable.kind(), consumable.name()),
+            ShopItem::Card(card) => write!(f, "[Playing Card] {}", card),
+        }
+    }
+}
+
+// Something on the shelf; tags can make it free
+pub struct ForSale<T> {
+    pub item: T,
+    pub free: bool,
+}
+
+impl<T> ForSale<T> {
+    pub fn new(item: T) -> Self {
+        Self { item, free: false }
+    }
+}
+
+pub struct Shop {
+    pub card_slots: usize,  // starts at 2
+    pub reroll_cost: i32,  // starts at the base cost each visit, +$1 per reroll
+    pub items: Vec<ForSale<ShopItem>>,
+    pub booster_slots: usize,  // starts at 2
+    pub boosters: Vec<ForSale<BoosterPack>>,  // not replaced by rerolls
+    pub vouchers: Vec<Voucher>,  // one per ante (more with Voucher Tags), not replaced by rerolls
+
+    // Settings changed by vouchers
+    pub base_reroll_cost: i32,  // starts at $5
+    pub discount: i32,  // percent off everything for sale
+    pub edition_rate: u32,  // multiplier on how often jokers have an edition
+    pub tarot_rate: u32,  // multiplier on how often tarot cards show up
+    pub planet_rate: u32,  // multiplier on how often planet cards show up
+    pub sells_playing_cards: bool,
+    pub enhanced_playing_cards: bool,
+
+    pub config: RunConfig,  // the run's stake decides which stickers jokers can come with
+}
+
+impl Shop {
+    pub fn new(config: RunConfig) -> Self {
+        Self {
+            card_slots: 2,
+            reroll_cost: 5,
+            items: Vec::new(),
+            booster_slots: 2,
+            boosters: Vec::new(),
+            vouchers: Vec::new(),
+            base_reroll_cost: 5,
+            discount: 0,
+            edition_rate: 1,
+            tarot_rate: 1,
+            planet_rate: 1,
+            sells_playing_cards: false,
+            enhanced_playing_cards: false,
+            config,
+        }
+    }
+
+    // Stock the shop for a new visit
+    pub fn restock(&mut self, owned_jokers: &[Joker]) {
+        self.reroll_cost = self.base_reroll_cost;
+        self.fill(owned_jokers);
+        self.boosters = (0..self.booster_slots).map(|_| ForSale::new(BoosterPack::random())).collect();
+    }
+
+    // Offer a new voucher in place of any left unbought; called once per ante
+    pub fn restock_voucher(&mut self, redeemed: &[Voucher]) {
+        self.vouchers = Voucher::random(redeemed).into_iter().collect();
+    }
+
+    // Offer an extra voucher alongside the current one (e.g. from a Voucher Tag)
+    pub fn add_voucher(&mut self, redeemed: &[Voucher]) {
+        let mut unavailable = redeemed.to_vec();
+        unavailable.extend(&self.vouchers);
+        if let Some(voucher) = Voucher::random(&unavailable) {
+            self.vouchers.push(voucher);
+        }
+    }
+
+    // Replace the cards for sale; the caller is responsible for charging the reroll cost
+    pub fn reroll(&mut self, owned_jokers: &[Joker]) {
+        self.reroll_cost += 1;
+        self.fill(owned_jokers);
+    }
+
+    // Lower the reroll cost for this and future visits (Reroll Surplus, Reroll Glut). Rerolls never pay out,
+    // even if they were already free (D6 Tag)
+    pub fn discount_rerolls(&mut self, amount: i32) {
+        self.base_reroll_cost = (self.base_reroll_cost - amount).max(0);
+        self.reroll_cost = (self.reroll_cost - amount).max(0);
+    }
+
+    // What something actually costs after any discount
+    pub fn price(&self, base_price: i32) -> i32 {
+        (base_price * (100 - self.discount) / 100).max(1)
+    }
+
+    pub fn item_price(&self, index: usize) -> i32 {
+        let slot = &self.items[index];
+        if slot.free { 0 } else { self.price(slot.item.price()) }
+    }
+
+    pub fn booster_price(&self, index: usize) -> i32 {
+        let slot = &self.boosters[index];
+        if slot.free { 0 } else { self.price(slot.item.price()) }
+    }
+
+    fn fill(&mut self, owned_jokers: &[Joker]) {
+        self.items = (0..self.card_slots)
+            .map(|_| ForSale::new(self.random_item(owned_jokers)))
+            .collect();
+    }
+
+    // Jokers show up 5 times as often as tarot, planet or playing cards, unless vouchers say otherwise
+    fn random_item(&self, owned_jokers: &[Joker]) -> ShopItem {
+        let mut rng = rand::thread_rng();
+        let tarot_weight = 4 * self.tarot_rate;
+        let planet_weight = 4 * self.planet_rate;
+        let card_weight = if self.sells_playing_cards { 4 } else { 0 };
+        let roll = rng.gen_range(0..20 + tarot_weight + planet_weight + card_weight);
+        if roll < 20 {
+            let mut joker = Joker::new(JokerFactory::random_joker(Rarity::random(), owned_jokers));
+            joker.edition = self.random_joker_edition();
+            self.config.apply_stickers(&mut joker);
+            ShopItem::Joker(joker)
+        } else if roll < 20 + tarot_weight {
+            ShopItem::Consumable(ConsumableFactory::create_consumable(TAROTS.choose(&mut rng).unwrap()))
+        } else if roll < 20 + tarot_weight + planet_weight {
+            let (planet, _) = PLANETS.choose(&mut rng).unwrap();
+            ShopItem::Consumable(ConsumableFactory::create_consumable(planet))
+        } else {
+            ShopItem::Card(self.random_playing_card())
+        }
+    }
+
+    // Most jokers for sale have no edition
+    fn random_joker_edition(&self) -> Option<Edition> {
+        let mut rng = rand::thread_rng();
+        let rate = self.edition_rate;
+        let roll = rng.gen_range(0..1000);
+        if roll < 3 {
+            Some(Edition::Negative)
+        } else if roll < 3 + 3 * rate {
+            Some(Edition::Polychrome)
+        } else if roll < 3 + 17 * rate {
+            Some(Edition::Holographic)
+        } else if roll < 3 + 37 * rate {
+            Some(Edition::Foil)
+        } else {
+            None
+        }
+    }
+
+    // Plain playing cards, unless Illusion has been redeemed
+    fn random_playing_card(&self) -> Card {
+        if self.enhanced_playing_cards {
+            Card::random(60, 20, 20)
+        } else {
+            Card::random(0, 0, 0)
+        }
+    }
+
+    pub fn print(&self) {
+        println!("\nFor sale:");
+        if self.items.is_empty() {
+            println!("Sold out!");
+        }
+        for (i, slot) in self.items.iter().enumerate() {
+            println!(
+                "[{}] {} {}: {}",
+                i,
+                slot.item,
+                format!("${}", self.item_price(i)).yellow(),
+                slot.item.description(),
+            );
+        }
+        println!("\nBooster packs:");
+        if self.boosters.is_empty() {
+            println!("Sold out!");
+        }
+        for (i, slot) in self.boosters.iter().enumerate() {
+            println!(
+                "[{}] {} {}: {}",
+                i,
+                slot.item,
+                format!("${}", self.booster_price(i)).yellow(),
+                slot.item.description(),
+            );
+        }
+        println!("\nVouchers:");
+        if self.vouchers.is_empty() {
+            println!("Sold out!");
+        }
+        for (i, voucher) in self.vouchers.iter().enumerate() {
+            println!("[{}] {} {}: {}", i, voucher, format!("${}", self.price(voucher.cost())).yellow(), voucher.description());
+        }
+        println!("\nReroll: {}", format!("${}", self.reroll_cost).yellow());
+    }
+}
+
+impl Default for Shop {
+    fn default() -> Self {
+        Self::new(RunConfig::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reroll_discount_after_d6_stays_free() {
+        let mut shop = Shop { reroll_cost: 0, ..Shop::default() };  // D6 Tag
+        shop.discount_rerolls(2);
+        assert_eq!(shop.reroll_cost, 0);
+        assert_eq!(shop.base_reroll_cost, 3);
+
+        shop.reroll(&[]);
+        assert_eq!(shop.reroll_cost, 1);
+    }
+}
@@ -0,0 +1,106 @@
+use colored::*;
+use rand::Rng;
+
+use crate::jokers::Joker;
+
+// Difficulty levels for a run. Each stake also includes every modifier of the stakes below it
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Stake {
+    #[default]
+    White,
+    Red,
+    Green,
+    Black,
+    Blue,
+    Purple,
+    Orange,
+    Gold,
+}
+
+impl Stake {
+    pub const ALL: [Stake; 8] = [
+        Stake::White,
+        Stake::Red,
+        Stake::Green,
+        Stake::Black,
+        Stake::Blue,
+        Stake::Purple,
+        Stake::Orange,
+        Stake::Gold,
+    ];
+
+    // The modifier this stake adds on top of the ones below it
+    pub fn description(&self) -> &'static str {
+        match self {
+            Stake::White => "Base difficulty",
+            Stake::Red => "Small Blind gives no reward money",
+            Stake::Green => "Required score scales faster for each Ante",
+            Stake::Black => "Shop can have Eternal Jokers (can't be sold or destroyed)",
+            Stake::Blue => "-1 Discard",
+            Stake::Purple => "Required score scales faster for each Ante",
+            Stake::Orange => "Shop can have Perishable Jokers (debuffed after 5 Rounds)",
+            Stake::Gold => "Shop can have Rental Jokers (costs $3 per round)",
+        }
+    }
+}
+
+impl std::fmt::Display for Stake {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Stake::White => "White Stake".white(),
+            Stake::Red => "Red Stake".red(),
+            Stake::Green => "Green Stake".green(),
+            Stake::Black => "Black Stake".bright_black(),
+            Stake::Blue => "Blue Stake".blue(),
+            Stake::Purple => "Purple Stake".purple(),
+            Stake::Orange => "Orange Stake".truecolor(255, 165, 0),
+            Stake::Gold => "Gold Stake".yellow(),
+        };
+        write!(f, "{}", name)
+    }
+}
+
+// Settings chosen at the start of a run that the rest of the game checks instead of the stake itself
+#[derive(Clone, Copy, Default)]
+pub struct RunConfig {
+    pub stake: Stake,
+}
+
+impl RunConfig {
+    pub fn new(stake: Stake) -> Self {
+        Self { stake }
+    }
+
+    pub fn small_blind_reward(&self) -> bool {
+        self.stake < Stake::Red
+    }
+
+    // Which curve of required scores to use (1 is the gentlest)
+    pub fn ante_scaling(&self) -> u8 {
+        if self.stake >= Stake::Purple {
+            3
+        } else if self.stake >= Stake::Green {
+            2
+        } else {
+            1
+        }
+    }
+
+    pub fn starting_discards(&self) -> u8 {
+        if self.stake >= Stake::Blue { 2 } else { 3 }
+    }
+
+    // Randomly apply Eternal, Perishable and Rental stickers to a joker for sale, as the stake allows
+    pub fn apply_stickers(&self, joker: &mut Joker) {
+        let mut rng = rand::thread_rng();
+        let roll = rng.gen_range(0..10);
+        if self.stake >= Stake::Black && roll < 3 {
+            joker.eternal = true;
+        } else if self.stake >= Stake::Orange && roll < 6 {
+            joker.perishable_rounds = Some(5);
+        }
+        if self.stake >= Stake::Gold && rng.gen_range(0..10) < 3 {
+            joker.rental = true;
+        }
+    }
+}
@@ -0,0 +1,143 @@
+use colored::*;
+use rand::seq::SliceRandom;
+
+use crate::PokerHand;
+
+// Rewards for skipping a Small or Big blind. Each one waits until its moment comes up
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Tag {
+    Uncommon,
+    Rare,
+    Foil,
+    Holographic,
+    Polychrome,
+    Investment,
+    Voucher,
+    Boss,
+    Standard,
+    Charm,
+    Meteor,
+    Buffoon,
+    Ethereal,
+    Double,
+    Juggle,
+    D6,
+    Coupon,
+    Economy,
+    Speed,
+    Handy,
+    Garbage,
+    Orbital(PokerHand),
+}
+
+// When a tag takes effect
+#[derive(PartialEq)]
+pub enum TagTiming {
+    Immediate,  // as soon as the blind is skipped
+    NextTag,  // when the next tag is gained (Double Tag)
+    RoundStart,
+    BossDefeated,
+    ShopEnter,
+}
+
+impl Tag {
+    // A random tag to offer for skipping a blind; some only show up from ante 2
+    pub fn random(ante: u8) -> Tag {
+        let mut rng = rand::thread_rng();
+        let mut tags = vec![
+            Tag::Uncommon,
+            Tag::Rare,
+            Tag::Foil,
+            Tag::Holographic,
+            Tag::Polychrome,
+            Tag::Investment,
+            Tag::Voucher,
+            Tag::Boss,
+            Tag::Charm,
+            Tag::Double,
+            Tag::Juggle,
+            Tag::D6,
+            Tag::Coupon,
+            Tag::Economy,
+            Tag::Speed,
+        ];
+        if ante >= 2 {
+            // Secret hands can't be upgraded by Orbital Tags
+            let hand = *PokerHand::ALL[3..].choose(&mut rng).unwrap();
+            tags.extend([Tag::Standard, Tag::Meteor, Tag::Buffoon, Tag::Ethereal, Tag::Handy, Tag::Garbage, Tag::Orbital(hand)]);
+        }
+        *tags.choose(&mut rng).unwrap()
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Tag::Uncommon => "Uncommon Tag",
+            Tag::Rare => "Rare Tag",
+            Tag::Foil => "Foil Tag",
+            Tag::Holographic => "Holographic Tag",
+            Tag::Polychrome => "Polychrome Tag",
+            Tag::Investment => "Investment Tag",
+            Tag::Voucher => "Voucher Tag",
+            Tag::Boss => "Boss Tag",
+            Tag::Standard => "Standard Tag",
+            Tag::Charm => "Charm Tag",
+            Tag::Meteor => "Meteor Tag",
+            Tag::Buffoon => "Buffoon Tag",
+            Tag::Ethereal => "Ethereal Tag",
+            Tag::Double => "Double Tag",
+            Tag::Juggle => "Juggle Tag",
+            Tag::D6 => "D6 Tag",
+            Tag::Coupon => "Coupon Tag",
+            Tag::Economy => "Economy Tag",
+            Tag::Speed => "Speed Tag",
+            Tag::Handy => "Handy Tag",
+            Tag::Garbage => "Garbage Tag",
+            Tag::Orbital(_) => "Orbital Tag",
+        }
+    }
+
+    pub fn description(&self) -> String {
+        match self {
+            Tag::Uncommon => "Shop has a free Uncommon Joker".to_string(),
+            Tag::Rare => "Shop has a free Rare Joker".to_string(),
+            Tag::Foil => format!("Next base edition shop Joker is free and becomes {}", "Foil".blue()),
+            Tag::Holographic => format!("Next base edition shop Joker is free and becomes {}", "Holographic".red()),
+            Tag::Polychrome => format!("Next base edition shop Joker is free and becomes {}", "Polychrome".magenta()),
+            Tag::Investment => format!("Gain {} after defeating the Boss Blind", "$25".yellow()),
+            Tag::Voucher => "Adds one Voucher to the next shop".to_string(),
+            Tag::Boss => "Rerolls the Boss Blind".to_string(),
+            Tag::Standard => "Gives a free Mega Standard Pack".to_string(),
+            Tag::Charm => "Gives a free Mega Arcana Pack".to_string(),
+            Tag::Meteor => "Gives a free Mega Celestial Pack".to_string(),
+            Tag::Buffoon => "Gives a free Mega Buffoon Pack".to_string(),
+            Tag::Ethereal => "Gives a free Spectral Pack".to_string(),
+            Tag::Double => "Gives a copy of the next selected Tag (Double Tag excluded)".to_string(),
+            Tag::Juggle => format!("{} hand size next round", "+3".yellow()),
+            Tag::D6 => format!("Rerolls in next shop start at {}", "$0".yellow()),
+            Tag::Coupon => "Initial cards and booster packs in next shop are free".to_string(),
+            Tag::Economy => format!("Doubles your money (Max of {})", "$40".yellow()),
+            Tag::Speed => format!("Gives {} for each Blind you've skipped this run", "$5".yellow()),
+            Tag::Handy => format!("Gives {} for each hand played this run", "$1".yellow()),
+            Tag::Garbage => format!("Gives {} for each unused discard this run", "$1".yellow()),
+            Tag::Orbital(hand) => format!("Upgrade {} by 3 levels", hand.to_string().bold()),
+        }
+    }
+
+    pub fn timing(&self) -> TagTiming {
+        match self {
+            Tag::Uncommon | Tag::Rare | Tag::Foil | Tag::Holographic | Tag::Polychrome
+            | Tag::Voucher | Tag::D6 | Tag::Coupon => TagTiming::ShopEnter,
+            Tag::Investment => TagTiming::BossDefeated,
+            Tag::Juggle => TagTiming::RoundStart,
+            Tag::Double => TagTiming::NextTag,
+            Tag::Boss | Tag::Standard | Tag::Charm | Tag::Meteor | Tag::Buffoon | Tag::Ethereal
+            | Tag::Economy | Tag::Speed | Tag::Handy | Tag::Garbage | Tag::Orbital(_) => TagTiming::Immediate,
+        }
+    }
+}
+
+impl std::fmt::Display for Tag {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name().bold())
+    }
+}
@@ -0,0 +1,178 @@
+use colored::*;
+use rand::seq::SliceRandom;
+
+// Permanent upgrades for the rest of the run, one offered per ante in the shop.
+// Each tier 2 voucher only shows up once its tier 1 voucher has been redeemed
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Voucher {
+    Overstock,
+    OverstockPlus,
+    ClearanceSale,
+    Liquidation,
+    Hone,
+    GlowUp,
+    RerollSurplus,
+    RerollGlut,
+    CrystalBall,
+    OmenGlobe,
+    Telescope,
+    Observatory,
+    Grabber,
+    NachoTong,
+    Wasteful,
+    Recyclomancy,
+    TarotMerchant,
+    TarotTycoon,
+    PlanetMerchant,
+    PlanetTycoon,
+    SeedMoney,
+    MoneyTree,
+    Blank,
+    Antimatter,
+    MagicTrick,
+    Illusion,
+    Hieroglyph,
+    Petroglyph,
+    PaintBrush,
+    Palette,
+    DirectorsCut,
+    Retcon,
+}
+
+impl Voucher {
+    pub const ALL: [Voucher; 32] = [
+        Voucher::Overstock,
+        Voucher::OverstockPlus,
+        Voucher::ClearanceSale,
+        Voucher::Liquidation,
+        Voucher::Hone,
+        Voucher::GlowUp,
+        Voucher::RerollSurplus,
+        Voucher::RerollGlut,
+        Voucher::CrystalBall,
+        Voucher::OmenGlobe,
+        Voucher::Telescope,
+        Voucher::Observatory,
+        Voucher::Grabber,
+        Voucher::NachoTong,
+        Voucher::Wasteful,
+        Voucher::Recyclomancy,
+        Voucher::TarotMerchant,
+        Voucher::TarotTycoon,
+        Voucher::PlanetMerchant,
+        Voucher::PlanetTycoon,
+        Voucher::SeedMoney,
+        Voucher::MoneyTree,
+        Voucher::Blank,
+        Voucher::Antimatter,
+        Voucher::MagicTrick,
+        Voucher::Illusion,
+        Voucher::Hieroglyph,
+        Voucher::Petroglyph,
+        Voucher::PaintBrush,
+        Voucher::Palette,
+        Voucher::DirectorsCut,
+        Voucher::Retcon,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Voucher::Overstock => "Overstock",
+            Voucher::OverstockPlus => "Overstock Plus",
+            Voucher::ClearanceSale => "Clearance Sale",
+            Voucher::Liquidation => "Liquidation",
+            Voucher::Hone => "Hone",
+            Voucher::GlowUp => "Glow Up",
+            Voucher::RerollSurplus => "Reroll Surplus",
+            Voucher::RerollGlut => "Reroll Glut",
+            Voucher::CrystalBall => "Crystal Ball",
+            Voucher::OmenGlobe => "Omen Globe",
+            Voucher::Telescope => "Telescope",
+            Voucher::Observatory => "Observatory",
+            Voucher::Grabber => "Grabber",
+            Voucher::NachoTong => "Nacho Tong",
+            Voucher::Wasteful => "Wasteful",
+            Voucher::Recyclomancy => "Recyclomancy",
+            Voucher::TarotMerchant => "Tarot Merchant",
+            Voucher::TarotTycoon => "Tarot Tycoon",
+            Voucher::PlanetMerchant => "Planet Merchant",
+            Voucher::PlanetTycoon => "Planet Tycoon",
+            Voucher::SeedMoney => "Seed Money",
+            Voucher::MoneyTree => "Money Tree",
+            Voucher::Blank => "Blank",
+            Voucher::Antimatter => "Antimatter",
+            Voucher::MagicTrick => "Magic Trick",
+            Voucher::Illusion => "Illusion",
+            Voucher::Hieroglyph => "Hieroglyph",
+            Voucher::Petroglyph => "Petroglyph",
+            Voucher::PaintBrush => "Paint Brush",
+            Voucher::Palette => "Palette",
+            Voucher::DirectorsCut => "Director's Cut",
+            Voucher::Retcon => "Retcon",
+        }
+    }
+
+    pub fn description(&self) -> String {
+        match self {
+            Voucher::Overstock | Voucher::OverstockPlus => "+1 card slot available in shop".to_string(),
+            Voucher::ClearanceSale => "All cards and packs in shop are 25% off".to_string(),
+            Voucher::Liquidation => "All cards and packs in shop are 50% off".to_string(),
+            Voucher::Hone => format!("{}, {} and {} cards appear 2X more often", "Foil".blue(), "Holographic".red(), "Polychrome".magenta()),
+            Voucher::GlowUp => format!("{}, {} and {} cards appear 4X more often", "Foil".blue(), "Holographic".red(), "Polychrome".magenta()),
+            Voucher::RerollSurplus | Voucher::RerollGlut => format!("Rerolls cost {} less", "$2".yellow()),
+            Voucher::CrystalBall => "+1 consumable slot".to_string(),
+            Voucher::OmenGlobe => "Spectral cards may appear in any of the Arcana Packs".to_string(),
+            Voucher::Telescope => "Celestial Packs always contain the Planet card for your most played poker hand".to_string(),
+            Voucher::Observatory => format!("Planet cards in your consumable area give {} Mult for their specified poker hand", "X1.5".red()),
+            Voucher::Grabber | Voucher::NachoTong => format!("Permanently gain {} hand each round", "+1".blue()),
+            Voucher::Wasteful | Voucher::Recyclomancy => format!("Permanently gain {} discard each round", "+1".red()),
+            Voucher::TarotMerchant => "Tarot cards appear 2X more frequently in the shop".to_string(),
+            Voucher::TarotTycoon => "Tarot cards appear 4X more frequently in the shop".to_string(),
+            Voucher::PlanetMerchant => "Planet cards appear 2X more frequently in the shop".to_string(),
+            Voucher::PlanetTycoon => "Planet cards appear 4X more frequently in the shop".to_string(),
+            Voucher::SeedMoney => format!("Raise the cap on interest earned in each round to {}", "$10".yellow()),
+            Voucher::MoneyTree => format!("Raise the cap on interest earned in each round to {}", "$20".yellow()),
+            Voucher::Blank => "Does nothing?".to_string(),
+            Voucher::Antimatter => "+1 Joker slot".to_string(),
+            Voucher::MagicTrick => "Playing cards can be purchased from the shop".to_string(),
+            Voucher::Illusion => "Playing cards in shop may have an Enhancement, Edition, and/or a Seal".to_string(),
+            Voucher::Hieroglyph => format!("-1 Ante, {} hand each round", "-1".blue()),
+            Voucher::Petroglyph => format!("-1 Ante, {} discard each round", "-1".red()),
+            Voucher::PaintBrush | Voucher::Palette => "+1 hand size".to_string(),
+            Voucher::DirectorsCut => format!("Reroll the Boss Blind 1 time per Ante, {} per roll", "$10".yellow()),
+            Voucher::Retcon => format!("Reroll the Boss Blind unlimited times, {} per roll", "$10".yellow()),
+        }
+    }
+
+    pub fn cost(&self) -> i32 {
+        10
+    }
+
+    // The tier 1 voucher that has to be redeemed before this one can appear
+    pub fn requires(&self) -> Option<Voucher> {
+        let idx = Voucher::ALL.iter().position(|voucher| voucher == self).unwrap();
+        if idx % 2 == 1 {
+            Some(Voucher::ALL[idx - 1])
+        } else {
+            None
+        }
+    }
+
+    // A voucher that hasn't been redeemed yet and is unlocked, if there are any left
+    pub fn random(redeemed: &[Voucher]) -> Option<Voucher> {
+        let mut rng = rand::thread_rng();
+        let available: Vec<Voucher> = Voucher::ALL
+            .iter()
+            .filter(|voucher| !redeemed.contains(voucher))
+            .filter(|voucher| voucher.requires().is_none_or(|tier_1| redeemed.contains(&tier_1)))
+            .copied()
+            .collect();
+        available.choose(&mut rng).copied()
+    }
+}
+
+impl std::fmt::Display for Voucher {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name().bold())
+    }
+}